@@ -0,0 +1,389 @@
+//! Backup, export, import, integrity checks and statistics of a [`Database`](crate::Database).
+//!
+//! The operations run synchronously on the server: the HTTP API does not stream their
+//! progress, which is logged and can be followed with
+//! [`ArcadeDB::server_events`](crate::ArcadeDB::server_events). Statistics reported by the
+//! server are available in the returned summaries.
+//!
+//! Paths are resolved by the server, whose working directory the client does not know, so
+//! files must be absolute: either plain paths such as `/exports/movies.jsonl.tgz`, turned
+//! into `file://` urls, or `file:///` urls. Urls with other schemes, such as `https://`,
+//! are passed as is.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{error::InvalidIdentifier, sql::sql_value, RecordID};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Jsonl,
+    GraphML,
+    GraphSON,
+}
+
+impl ExportFormat {
+    fn keyword(&self) -> &'static str {
+        match self {
+            ExportFormat::Jsonl => "JSONL",
+            ExportFormat::GraphML => "GRAPHML",
+            ExportFormat::GraphSON => "GRAPHSON",
+        }
+    }
+}
+
+/// Settings of an `IMPORT DATABASE`, e.g. `commitEvery` or `parallel`. Keys are limited
+/// to `[A-Za-z0-9_]` since the server cannot quote them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportOptions {
+    settings: Vec<(String, Value)>,
+}
+
+impl ImportOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn setting(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.settings.push((key.into(), value.into()));
+        self
+    }
+    pub fn commit_every(self, records: u64) -> Self {
+        self.setting("commitEvery", records)
+    }
+    pub fn parallel(self, threads: u32) -> Self {
+        self.setting("parallel", threads)
+    }
+    pub fn settings(&self) -> &[(String, Value)] {
+        &self.settings
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupSummary {
+    pub operation: String,
+    pub backup_file: Option<String>,
+    #[serde(flatten)]
+    pub statistics: HashMap<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSummary {
+    pub operation: String,
+    pub to_url: Option<String>,
+    #[serde(flatten)]
+    pub statistics: HashMap<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSummary {
+    pub operation: String,
+    pub from_url: Option<String>,
+    #[serde(flatten)]
+    pub statistics: HashMap<String, Value>,
+}
+
+/// The result of `CHECK DATABASE`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckReport {
+    #[serde(default)]
+    pub auto_fix: bool,
+    #[serde(default)]
+    pub total_errors: u64,
+    #[serde(default)]
+    pub total_active_records: u64,
+    #[serde(default)]
+    pub total_deleted_records: u64,
+    #[serde(default)]
+    pub corrupted_records: Vec<RecordID>,
+    #[serde(default)]
+    pub missing_reference_back: Vec<Value>,
+    #[serde(default)]
+    pub invalid_links: Vec<Value>,
+    #[serde(default)]
+    pub buckets: Vec<String>,
+    #[serde(default)]
+    pub warnings: Vec<Value>,
+    #[serde(flatten)]
+    pub statistics: HashMap<String, Value>,
+}
+
+impl CheckReport {
+    /// Returns `true` if the check found no errors, corrupted records or broken links.
+    pub fn is_healthy(&self) -> bool {
+        self.total_errors == 0
+            && self.corrupted_records.is_empty()
+            && self.missing_reference_back.is_empty()
+            && self.invalid_links.is_empty()
+    }
+}
+
+/// Record counts and page cache usage of a database.
+///
+/// Counts are the `records` reported by `schema:types` and `schema:buckets`. The page
+/// cache is shared by all the databases of a server, so [`PageCacheStats`] is server-wide
+/// and only readable by the root user: it is `None` when the server metrics are denied.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DatabaseStats {
+    pub types: HashMap<String, u64>,
+    pub buckets: HashMap<String, u64>,
+    /// The operation counters returned by `select from schema:stats`.
+    pub operations: HashMap<String, Value>,
+    pub page_cache: Option<PageCacheStats>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PageCacheStats {
+    pub read_cache_used: Option<u64>,
+    pub write_cache_used: Option<u64>,
+    pub cache_max: Option<u64>,
+    pub pages_read: Option<u64>,
+    pub pages_written: Option<u64>,
+}
+
+impl PageCacheStats {
+    /// Reads the cache entries of the `profiler` server metrics, which are either numbers
+    /// or objects like `{ "space": 1024 }` or `{ "count": 12 }`.
+    pub(crate) fn from_metrics(metrics: Option<&HashMap<String, Value>>) -> Self {
+        let profiler = metrics.and_then(|metrics| metrics.get("profiler"));
+        let metric = |key: &str| {
+            profiler
+                .and_then(|profiler| profiler.get(key))
+                .and_then(|value| match value {
+                    Value::Object(entries) => entries
+                        .get("space")
+                        .or_else(|| entries.get("count"))
+                        .and_then(Value::as_u64),
+                    value => value.as_u64(),
+                })
+        };
+        Self {
+            read_cache_used: metric("readCacheUsed"),
+            write_cache_used: metric("writeCacheUsed"),
+            cache_max: metric("cacheMax"),
+            pages_read: metric("pagesRead"),
+            pages_written: metric("pagesWritten"),
+        }
+    }
+}
+
+/// A row of `select name, records from schema:types` or `schema:buckets`.
+#[derive(Deserialize)]
+pub(crate) struct Count {
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) records: u64,
+}
+
+/// Turns a plain path into a `file://` url, rejects relative paths and `file://` urls, and
+/// rejects characters that cannot appear in an unquoted url.
+pub(crate) fn url(url_or_path: &str) -> Result<String, anyhow::Error> {
+    if url_or_path.is_empty() {
+        return Err(anyhow::anyhow!("Url cannot be empty"));
+    }
+    if let Some(c) = url_or_path
+        .chars()
+        .find(|c| c.is_whitespace() || c.is_control() || matches!(c, '\'' | '"' | '`' | ';'))
+    {
+        return Err(anyhow::anyhow!(
+            "Url {:?} contains invalid character {:?}",
+            url_or_path,
+            c
+        ));
+    }
+    let path = match url_or_path.split_once("://") {
+        Some(("file", path)) => path,
+        Some(_) => return Ok(url_or_path.to_string()),
+        None => url_or_path,
+    };
+    if path.starts_with('/') {
+        Ok(format!("file://{}", path))
+    } else {
+        Err(anyhow::anyhow!(
+            "Path {:?} must be absolute, e.g. file:///{}",
+            url_or_path,
+            path
+        ))
+    }
+}
+
+fn setting_key(key: &str) -> Result<&str, InvalidIdentifier> {
+    if key.is_empty() {
+        return Err(InvalidIdentifier::Empty);
+    }
+    match key
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '_'))
+    {
+        Some(c) => Err(InvalidIdentifier::InvalidCharacter(key.to_string(), c)),
+        None => Ok(key),
+    }
+}
+
+pub(crate) fn backup_sql(url_or_path: Option<&str>) -> Result<String, anyhow::Error> {
+    match url_or_path {
+        Some(url_or_path) => Ok(format!("BACKUP DATABASE {}", url(url_or_path)?)),
+        None => Ok(String::from("BACKUP DATABASE")),
+    }
+}
+
+pub(crate) fn export_sql(
+    format: ExportFormat,
+    url_or_path: &str,
+    overwrite: bool,
+) -> Result<String, anyhow::Error> {
+    Ok(format!(
+        "EXPORT DATABASE {} FORMAT {} OVERWRITE {}",
+        url(url_or_path)?,
+        format.keyword(),
+        overwrite
+    ))
+}
+
+pub(crate) fn import_sql(
+    url_or_path: &str,
+    options: &ImportOptions,
+) -> Result<String, anyhow::Error> {
+    let mut sql = format!("IMPORT DATABASE {}", url(url_or_path)?);
+    if !options.settings.is_empty() {
+        let settings = options
+            .settings
+            .iter()
+            .map(|(key, value)| {
+                setting_key(key).map(|key| format!("{} = {}", key, sql_value(value)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        sql.push_str(" WITH ");
+        sql.push_str(&settings.join(", "));
+    }
+    Ok(sql)
+}
+
+pub(crate) fn check_sql(fix: bool) -> String {
+    if fix {
+        String::from("CHECK DATABASE FIX")
+    } else {
+        String::from("CHECK DATABASE")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use crate::RecordID;
+
+    use super::{
+        backup_sql, check_sql, export_sql, import_sql, CheckReport, ExportFormat, ExportSummary,
+        ImportOptions, PageCacheStats,
+    };
+
+    #[test]
+    fn should_render_admin_statements() {
+        assert_eq!("BACKUP DATABASE", backup_sql(None).unwrap());
+        assert_eq!(
+            "BACKUP DATABASE file:///backups/movies.zip",
+            backup_sql(Some("/backups/movies.zip")).unwrap()
+        );
+        assert_eq!(
+            "EXPORT DATABASE file:///movies.graphml FORMAT GRAPHML OVERWRITE true",
+            export_sql(ExportFormat::GraphML, "file:///movies.graphml", true).unwrap()
+        );
+        assert_eq!(
+            "IMPORT DATABASE https://example.com/movies.jsonl.tgz WITH commitEvery = 1000, parallel = 4",
+            import_sql(
+                "https://example.com/movies.jsonl.tgz",
+                &ImportOptions::new().commit_every(1000).parallel(4)
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_urls() {
+        assert!(backup_sql(Some("")).is_err());
+        assert!(export_sql(ExportFormat::Jsonl, "a.jsonl; DROP TYPE Movie", false).is_err());
+        assert!(export_sql(ExportFormat::Jsonl, "exports/a.jsonl", false).is_err());
+        assert!(export_sql(ExportFormat::Jsonl, "file://exports/a.jsonl", false).is_err());
+        assert!(import_sql("a'b", &ImportOptions::new()).is_err());
+    }
+
+    #[test]
+    fn should_reject_invalid_import_settings() {
+        let url = "file:///movies.jsonl";
+
+        assert!(import_sql(url, &ImportOptions::new().setting("a = 1, b", 2)).is_err());
+        assert!(import_sql(url, &ImportOptions::new().setting("a.b", 2)).is_err());
+        assert!(import_sql(url, &ImportOptions::new().setting("", 2)).is_err());
+    }
+
+    #[test]
+    fn should_deserialize_summaries() {
+        let summary: ExportSummary = serde_json::from_value(json!({
+            "operation": "export database",
+            "toUrl": "file://movies.jsonl.tgz",
+            "vertices": 171,
+            "edges": 253
+        }))
+        .unwrap();
+
+        assert_eq!(Some("file://movies.jsonl.tgz".to_string()), summary.to_url);
+        assert_eq!(Some(&json!(171)), summary.statistics.get("vertices"));
+    }
+
+    #[test]
+    fn should_deserialize_check_report() {
+        assert_eq!("CHECK DATABASE FIX", check_sql(true));
+
+        let report: CheckReport = serde_json::from_value(json!({
+            "operation": "check database",
+            "autoFix": false,
+            "totalErrors": 1,
+            "totalActiveRecords": 424,
+            "corruptedRecords": ["#12:3"],
+            "pageSize": 65536
+        }))
+        .unwrap();
+
+        assert!(!report.is_healthy());
+        assert_eq!(vec![RecordID::new(12, 3)], report.corrupted_records);
+        assert!(report.invalid_links.is_empty());
+        assert_eq!(Some(&json!(65536)), report.statistics.get("pageSize"));
+    }
+
+    #[test]
+    fn should_read_page_cache_metrics() {
+        let metrics: HashMap<_, _> = serde_json::from_value(json!({
+            "profiler": {
+                "readCacheUsed": { "space": 1024 },
+                "cacheMax": { "space": 4096 },
+                "pagesRead": { "count": 12, "perMinute": 1 },
+                "writeCacheUsed": { "perMinute": 5 },
+                "pagesWritten": 3
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            PageCacheStats {
+                read_cache_used: Some(1024),
+                write_cache_used: None,
+                cache_max: Some(4096),
+                pages_read: Some(12),
+                pages_written: Some(3),
+            },
+            PageCacheStats::from_metrics(Some(&metrics))
+        );
+        assert_eq!(
+            PageCacheStats::default(),
+            PageCacheStats::from_metrics(None)
+        );
+    }
+}
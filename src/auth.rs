@@ -0,0 +1,286 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::SystemTime,
+};
+
+use anyhow::Context;
+
+use crate::Auth;
+
+/// A source of credentials, consulted before every request so that they can rotate
+/// without rebuilding the client.
+///
+/// [`Auth`] is the static provider; [`EnvAuth`] and [`FileAuth`] read the environment
+/// and a file.
+///
+/// ```rust,no_run
+/// use arcadedb_rs::{ArcadeDB, EnvAuth};
+///
+/// # async fn run() -> anyhow::Result<()> {
+/// let arcadedb = ArcadeDB::builder()
+///     .auth_provider(EnvAuth::new())
+///     .build("http://localhost:2480")
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait::async_trait]
+pub trait AuthProvider: Send + Sync {
+    async fn auth(&self) -> anyhow::Result<Auth>;
+}
+
+#[async_trait::async_trait]
+impl AuthProvider for Auth {
+    async fn auth(&self) -> anyhow::Result<Auth> {
+        Ok(self.clone())
+    }
+}
+
+/// Reads a bearer token or basic credentials from environment variables, by default
+/// `ARCADEDB_TOKEN`, `ARCADEDB_USER` and `ARCADEDB_PASSWORD`. The token wins when set.
+/// With [`session`](EnvAuth::session) the credentials are exchanged for a session token.
+#[derive(Debug, Clone)]
+pub struct EnvAuth {
+    token_var: String,
+    user_var: String,
+    password_var: String,
+    session: bool,
+}
+
+impl Default for EnvAuth {
+    fn default() -> Self {
+        Self {
+            token_var: String::from("ARCADEDB_TOKEN"),
+            user_var: String::from("ARCADEDB_USER"),
+            password_var: String::from("ARCADEDB_PASSWORD"),
+            session: false,
+        }
+    }
+}
+
+impl EnvAuth {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn token_var(mut self, name: impl Into<String>) -> Self {
+        self.token_var = name.into();
+        self
+    }
+    pub fn user_var(mut self, name: impl Into<String>) -> Self {
+        self.user_var = name.into();
+        self
+    }
+    pub fn password_var(mut self, name: impl Into<String>) -> Self {
+        self.password_var = name.into();
+        self
+    }
+
+    pub fn session(mut self, session: bool) -> Self {
+        self.session = session;
+        self
+    }
+
+    fn auth_from_vars(&self, var: impl Fn(&str) -> Option<String>) -> anyhow::Result<Auth> {
+        let var = |name: &str| var(name).filter(|value| !value.is_empty());
+        match (
+            var(&self.token_var),
+            var(&self.user_var),
+            var(&self.password_var),
+        ) {
+            (Some(token), _, _) => Ok(Auth::bearer(token)),
+            (None, Some(user), Some(password)) if self.session => Ok(Auth::session(user, password)),
+            (None, Some(user), Some(password)) => Ok(Auth::basic(user, password)),
+            (None, Some(_), None) => Err(anyhow::anyhow!(
+                "Environment variable {} is not set",
+                self.password_var
+            )),
+            (None, None, _) => Ok(Auth::NoAuth),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthProvider for EnvAuth {
+    async fn auth(&self) -> anyhow::Result<Auth> {
+        self.auth_from_vars(|name| std::env::var(name).ok())
+    }
+}
+
+#[derive(Debug, Clone)]
+enum FileFormat {
+    Bearer,
+    Basic,
+    Password(String),
+}
+
+/// Reads credentials from a file, reloading it whenever its modification time
+/// changes, e.g. a mounted secret that gets rotated. The file is accessed on the
+/// blocking thread pool of the Tokio runtime.
+#[derive(Debug)]
+pub struct FileAuth {
+    path: PathBuf,
+    format: FileFormat,
+    cache: Mutex<Option<(SystemTime, Auth)>>,
+}
+
+impl FileAuth {
+    fn new(path: impl Into<PathBuf>, format: FileFormat) -> Self {
+        Self {
+            path: path.into(),
+            format,
+            cache: Mutex::new(None),
+        }
+    }
+    /// A file containing a bearer token.
+    pub fn bearer(path: impl Into<PathBuf>) -> Self {
+        Self::new(path, FileFormat::Bearer)
+    }
+    /// A file containing `<username>:<password>`.
+    pub fn basic(path: impl Into<PathBuf>) -> Self {
+        Self::new(path, FileFormat::Basic)
+    }
+    /// A file containing the password of `username`.
+    pub fn password(username: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        Self::new(path, FileFormat::Password(username.into()))
+    }
+
+    fn cache(&self) -> std::sync::MutexGuard<'_, Option<(SystemTime, Auth)>> {
+        self.cache.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Runs the file operation `f` without blocking the async runtime.
+    async fn blocking<T, F>(&self, f: F) -> anyhow::Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Path) -> std::io::Result<T> + Send + 'static,
+    {
+        let path = self.path.clone();
+        tokio::task::spawn_blocking(move || f(&path))
+            .await?
+            .with_context(|| format!("Cannot read credentials from {}", self.path.display()))
+    }
+
+    fn parse(&self, contents: &str) -> anyhow::Result<Auth> {
+        let contents = contents.trim_end_matches(['\r', '\n']);
+        match &self.format {
+            FileFormat::Bearer => Ok(Auth::bearer(contents.trim())),
+            FileFormat::Basic => contents
+                .split_once(':')
+                .map(|(user, password)| Auth::basic(user, password))
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Expected <username>:<password> in {}", self.path.display())
+                }),
+            FileFormat::Password(user) => Ok(Auth::basic(user, contents)),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthProvider for FileAuth {
+    async fn auth(&self) -> anyhow::Result<Auth> {
+        let modified = self
+            .blocking(|path| std::fs::metadata(path).and_then(|metadata| metadata.modified()))
+            .await?;
+
+        if let Some((cached, auth)) = self.cache().as_ref() {
+            if *cached == modified {
+                return Ok(auth.clone());
+            }
+        }
+        let contents = self.blocking(|path| std::fs::read_to_string(path)).await?;
+        let auth = self.parse(&contents)?;
+        *self.cache() = Some((modified, auth.clone()));
+        Ok(auth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Auth;
+
+    use super::{AuthProvider, EnvAuth, FileAuth};
+
+    fn basic(auth: Auth) -> Option<(String, String)> {
+        match auth {
+            Auth::Basic(credentials) => Some((
+                credentials.username,
+                credentials.password.expose().to_string(),
+            )),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn should_read_credentials_from_env() {
+        let provider = EnvAuth::new()
+            .user_var("USER")
+            .password_var("PASSWORD")
+            .token_var("TOKEN");
+        let vars = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+
+        assert!(matches!(
+            provider.auth_from_vars(vars(&[])).unwrap(),
+            Auth::NoAuth
+        ));
+        assert!(provider.auth_from_vars(vars(&[("USER", "root")])).is_err());
+        assert!(provider
+            .auth_from_vars(vars(&[("USER", "root"), ("PASSWORD", "")]))
+            .is_err());
+        assert_eq!(
+            Some(("root".to_string(), "playwithdata".to_string())),
+            basic(
+                provider
+                    .auth_from_vars(vars(&[("USER", "root"), ("PASSWORD", "playwithdata")]))
+                    .unwrap()
+            )
+        );
+        assert!(matches!(
+            provider.auth_from_vars(vars(&[("USER", "root"), ("TOKEN", "AU-1234")])).unwrap(),
+            Auth::Bearer(token) if token.expose() == "AU-1234"
+        ));
+        assert!(matches!(
+            provider
+                .clone()
+                .session(true)
+                .auth_from_vars(vars(&[("USER", "root"), ("PASSWORD", "playwithdata")]))
+                .unwrap(),
+            Auth::Session(credentials) if credentials.username == "root"
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_reload_rotated_file() {
+        let path = std::env::temp_dir().join(format!("arcadedb-auth-{}", std::process::id()));
+        std::fs::write(&path, "root:first\n").unwrap();
+        let provider = FileAuth::basic(&path);
+
+        assert_eq!(
+            Some(("root".to_string(), "first".to_string())),
+            basic(provider.auth().await.unwrap())
+        );
+
+        std::fs::write(&path, "root:second:with-colon\n").unwrap();
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(5))
+            .unwrap();
+        assert_eq!(
+            Some(("root".to_string(), "second:with-colon".to_string())),
+            basic(provider.auth().await.unwrap())
+        );
+
+        std::fs::write(&path, "no-separator").unwrap();
+        file.set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(10))
+            .unwrap();
+        assert!(provider.auth().await.is_err());
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(provider.auth().await.is_err());
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    env,
+    io::{self, BufRead},
+    process,
+};
+
+use arcadedb_rs::{
+    codegen::{self, DecimalType, Options},
+    ArcadeDBBuilder, Auth,
+};
+
+const USAGE: &str = "Usage: arcadedb codegen [<database>] [--url <url>] [--user <user>] [--password-stdin] [--decimal number|rust_decimal] [--output <file>]
+
+The url, database and credentials default to ARCADEDB_URL, ARCADEDB_DATABASE, ARCADEDB_USER
+and ARCADEDB_PASSWORD, and the url may be an arcadedb:// connection string. With --user or
+--password-stdin the password is read from the first line of stdin or from ARCADEDB_PASSWORD.
+DECIMAL properties become serde_json::Number, or rust_decimal::Decimal with --decimal rust_decimal.";
+
+#[tokio::main]
+async fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match args.first().map(String::as_str) {
+        Some("codegen") => run_codegen(&args[1..]).await,
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+async fn run_codegen(args: &[String]) -> Result<(), String> {
+    let mut database = None;
+    let mut url = None;
+    let mut user = None;
+    let mut password_stdin = false;
+    let mut options = Options::new();
+    let mut output = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().cloned().ok_or_else(|| USAGE.to_string());
+        match arg.as_str() {
+            "--url" => url = Some(value()?),
+            "--user" => user = Some(value()?),
+            "--password-stdin" => password_stdin = true,
+            "--decimal" => {
+                let decimal = match value()?.as_str() {
+                    "number" => DecimalType::Number,
+                    "rust_decimal" => DecimalType::RustDecimal,
+                    _ => return Err(USAGE.to_string()),
+                };
+                options = options.decimal(decimal);
+            }
+            "--output" => output = Some(value()?),
+            name if database.is_none() && !name.starts_with("--") => {
+                database = Some(name.to_string())
+            }
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    let builder = match &url {
+        Some(url) if url.starts_with("arcadedb:") => ArcadeDBBuilder::from_url(url),
+        _ => ArcadeDBBuilder::from_env(),
+    }
+    .map_err(|err| err.to_string())?;
+    let builder = if user.is_some() || password_stdin {
+        builder.auth(explicit_auth(user, password_stdin)?)
+    } else {
+        builder
+    };
+    let arcadedb = match url {
+        Some(url) if !url.starts_with("arcadedb:") => builder.build(url).await,
+        _ => builder.connect().await,
+    }
+    .map_err(|err| err.to_string())?;
+
+    let db = match database {
+        Some(database) => arcadedb.db(database),
+        None => arcadedb.default_db().ok_or_else(|| USAGE.to_string())?,
+    };
+    let schema = db.schema().await.map_err(|err| err.to_string())?;
+    let code = codegen::generate_with(&schema, &options);
+
+    match output {
+        Some(output) => std::fs::write(output, code).map_err(|err| err.to_string()),
+        None => {
+            print!("{}", code);
+            Ok(())
+        }
+    }
+}
+
+/// The credentials given with `--user` or `--password-stdin`, completed from the environment.
+fn explicit_auth(user: Option<String>, password_stdin: bool) -> Result<Auth, String> {
+    let user = user.or_else(|| env::var("ARCADEDB_USER").ok());
+    let password = if password_stdin {
+        let mut line = String::new();
+        io::stdin()
+            .lock()
+            .read_line(&mut line)
+            .map_err(|err| err.to_string())?;
+        Some(line.trim_end_matches(['\r', '\n']).to_string())
+    } else {
+        env::var("ARCADEDB_PASSWORD").ok()
+    };
+
+    match (user, password) {
+        (Some(user), Some(password)) => Ok(Auth::basic(user, password)),
+        _ => Err(String::from(
+            "A user needs a password from ARCADEDB_PASSWORD or --password-stdin",
+        )),
+    }
+}
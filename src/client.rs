@@ -1,14 +1,23 @@
-use std::sync::Arc;
+use std::{
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
 
 use anyhow::Result;
 use serde::de::DeserializeOwned;
 
 use crate::{
+    auth::AuthProvider,
     db::Database,
     error::{ArcadeDBError, ErrorResponse},
     options::{ArcadeDBOptions, Auth},
-    protocol::{DatabasesResponse, GetDatabasesRequest, Request},
+    protocol::{
+        DatabasesResponse, GenericResponse, GetDatabasesRequest, GetServerRequest, ReadyRequest,
+        Request, ServerCommand, ServerCommandRequest, ServerEvents, ServerEventsResponse,
+        ServerInfo, ServerMode, UserDefinition,
+    },
     transport::{ArcadeResponse, ReqwestTransport, Transport},
+    types::version::Capabilities,
 };
 
 #[derive(Clone)]
@@ -16,16 +25,72 @@ pub struct ArcadeDB(Arc<ArcadeDBInternal>);
 
 struct ArcadeDBInternal {
     transport: ReqwestTransport,
+    database: Option<String>,
+    capabilities: OnceLock<Capabilities>,
 }
 
 impl ArcadeDB {
     pub fn builder() -> ArcadeDBBuilder {
         ArcadeDBBuilder(ArcadeDBOptions::default())
     }
+    /// Connects with a connection string, see [`ArcadeDBOptions::from_url`].
+    ///
+    /// ```rust,no_run
+    /// use arcadedb_rs::ArcadeDB;
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let arcadedb = ArcadeDB::from_url("arcadedb://root:pw@localhost:2480/movies?timeout=5s").await?;
+    /// let movies = arcadedb.default_db().unwrap();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn from_url(url: &str) -> Result<ArcadeDB> {
+        ArcadeDBBuilder::from_url(url)?.connect().await
+    }
     async fn connect(opts: ArcadeDBOptions) -> Result<ArcadeDB> {
-        Ok(ArcadeDB(Arc::new(ArcadeDBInternal {
+        let eager_connect = opts.eager_connect;
+        let client = ArcadeDB(Arc::new(ArcadeDBInternal {
+            database: opts.database.clone(),
             transport: ReqwestTransport::new(opts),
-        })))
+            capabilities: OnceLock::new(),
+        }));
+        if eager_connect {
+            client.ping().await?;
+            client.negotiate().await?;
+        }
+        Ok(client)
+    }
+
+    /// The server capabilities, available once negotiated either eagerly in
+    /// [`ArcadeDBBuilder::build`] or with [`ArcadeDB::negotiate`].
+    pub fn capabilities(&self) -> Option<&Capabilities> {
+        self.0.capabilities.get()
+    }
+
+    /// Fetches the server version and capabilities, which also verifies the credentials.
+    /// The result is cached for the lifetime of the client.
+    pub async fn negotiate(&self) -> Result<&Capabilities, ArcadeDBError<ErrorResponse>> {
+        if let Some(capabilities) = self.capabilities() {
+            return Ok(capabilities);
+        }
+        // Only the default and cluster modes report the HA configuration, and only to
+        // the root user: other users get the basic information.
+        let (info, clustered) = match self.server_info(ServerMode::Default).await {
+            Ok(info) => {
+                let clustered = Some(info.ha.is_some());
+                (info, clustered)
+            }
+            Err(ArcadeDBError::Error(err)) if err.is_forbidden() => {
+                (self.server_info(ServerMode::Basic).await?, None)
+            }
+            Err(err) => return Err(err),
+        };
+        let capabilities = Capabilities {
+            version: info.server_version()?,
+            server_name: info.server_name,
+            clustered,
+        };
+        Ok(self.0.capabilities.get_or_init(|| capabilities))
     }
 
     pub async fn databases(&self) -> Result<DatabasesResponse, ArcadeDBError<ErrorResponse>> {
@@ -34,6 +99,122 @@ impl ArcadeDB {
             .map(|response| response.payload)
     }
 
+    pub async fn open_database(
+        &self,
+        name: &str,
+    ) -> Result<GenericResponse, ArcadeDBError<ErrorResponse>> {
+        self.server_command(ServerCommand::OpenDatabase(name)).await
+    }
+
+    pub async fn close_database(
+        &self,
+        name: &str,
+    ) -> Result<GenericResponse, ArcadeDBError<ErrorResponse>> {
+        self.server_command(ServerCommand::CloseDatabase(name))
+            .await
+    }
+
+    /// Aligns the replicas of `name` with the leader in a cluster.
+    pub async fn align_database(
+        &self,
+        name: &str,
+    ) -> Result<GenericResponse, ArcadeDBError<ErrorResponse>> {
+        self.server_command(ServerCommand::AlignDatabase(name))
+            .await
+    }
+
+    pub async fn server_info(
+        &self,
+        mode: ServerMode,
+    ) -> Result<ServerInfo, ArcadeDBError<ErrorResponse>> {
+        self.request(GetServerRequest::new(mode))
+            .await
+            .map(|response| response.payload)
+    }
+
+    /// Checks that the server is reachable and ready to accept requests.
+    pub async fn ping(&self) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        self.request_no_response(ReadyRequest)
+            .await
+            .map(|response| response.payload)
+    }
+
+    pub async fn ready(&self) -> bool {
+        self.ping().await.is_ok()
+    }
+
+    /// Ends the session opened with [`Auth::Session`]; the next request logs in again.
+    /// Dropping the last clone of the client also logs out, in the background.
+    pub async fn logout(&self) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        Ok(self.0.transport.logout().await?)
+    }
+
+    /// Creates a server user with its groups per database, see [`UserDefinition::database`].
+    ///
+    /// The server command endpoint can only create and drop users: listing users and
+    /// changing their password or groups are not available through it.
+    pub async fn create_user(
+        &self,
+        user: &UserDefinition,
+    ) -> Result<GenericResponse, ArcadeDBError<ErrorResponse>> {
+        self.server_command(ServerCommand::CreateUser(user))
+            .await
+            .map_err(|err| err.redact(&user.secrets()))
+    }
+
+    pub async fn drop_user(
+        &self,
+        name: &str,
+    ) -> Result<GenericResponse, ArcadeDBError<ErrorResponse>> {
+        self.server_command(ServerCommand::DropUser(name)).await
+    }
+
+    pub async fn set_server_setting(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> Result<GenericResponse, ArcadeDBError<ErrorResponse>> {
+        self.server_command(ServerCommand::SetServerSetting(key, value))
+            .await
+    }
+
+    pub async fn set_database_setting(
+        &self,
+        database: &str,
+        key: &str,
+        value: &str,
+    ) -> Result<GenericResponse, ArcadeDBError<ErrorResponse>> {
+        self.server_command(ServerCommand::SetDatabaseSetting(database, key, value))
+            .await
+    }
+
+    /// Returns the server events, from the current log or from one of the returned `files`.
+    pub async fn server_events(
+        &self,
+        file: Option<&str>,
+    ) -> Result<ServerEvents, ArcadeDBError<ErrorResponse>> {
+        self.request(ServerCommandRequest::<ServerEventsResponse>::new(
+            ServerCommand::GetServerEvents(file),
+        ))
+        .await
+        .map(|response| response.payload.result)
+    }
+
+    pub async fn shutdown(&self) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        self.request_no_response(ServerCommandRequest::<()>::new(ServerCommand::Shutdown))
+            .await
+            .map(|response| response.payload)
+    }
+
+    async fn server_command(
+        &self,
+        command: ServerCommand<'_>,
+    ) -> Result<GenericResponse, ArcadeDBError<ErrorResponse>> {
+        self.request(ServerCommandRequest::<GenericResponse>::new(command))
+            .await
+            .map(|response| response.payload)
+    }
+
     pub(crate) async fn request<T>(
         &self,
         request: T,
@@ -59,13 +240,60 @@ impl ArcadeDB {
     pub fn db(&self, name: impl Into<String>) -> Database {
         Database::new(self.clone(), name.into())
     }
+    /// The database named in the connection string or in `ARCADEDB_DATABASE`.
+    pub fn default_db(&self) -> Option<Database> {
+        self.0.database.as_ref().map(|name| self.db(name))
+    }
 }
 
 pub struct ArcadeDBBuilder(ArcadeDBOptions);
 
+impl From<ArcadeDBOptions> for ArcadeDBBuilder {
+    fn from(opts: ArcadeDBOptions) -> Self {
+        ArcadeDBBuilder(opts)
+    }
+}
+
 impl ArcadeDBBuilder {
+    /// Starts from a connection string, see [`ArcadeDBOptions::from_url`].
+    pub fn from_url(url: &str) -> Result<ArcadeDBBuilder> {
+        ArcadeDBOptions::from_url(url).map(ArcadeDBBuilder)
+    }
+    /// Starts from the environment, see [`ArcadeDBOptions::from_env`].
+    ///
+    /// ```rust,no_run
+    /// use arcadedb_rs::ArcadeDBBuilder;
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let arcadedb = ArcadeDBBuilder::from_env()?.eager_connect(true).connect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<ArcadeDBBuilder> {
+        ArcadeDBOptions::from_env().map(ArcadeDBBuilder)
+    }
     pub fn auth(mut self, auth: Auth) -> ArcadeDBBuilder {
-        self.0.auth = auth;
+        self.0.auth = Arc::new(auth);
+        self
+    }
+    /// Consults `provider` for the credentials of every request.
+    pub fn auth_provider(mut self, provider: impl AuthProvider + 'static) -> ArcadeDBBuilder {
+        self.0.auth = Arc::new(provider);
+        self
+    }
+    /// Checks in [`build`](ArcadeDBBuilder::build) that the server is ready and the
+    /// credentials are valid, and negotiates the server [`Capabilities`].
+    pub fn eager_connect(mut self, eager_connect: bool) -> ArcadeDBBuilder {
+        self.0.eager_connect = eager_connect;
+        self
+    }
+    pub fn timeout(mut self, timeout: Duration) -> ArcadeDBBuilder {
+        self.0.timeout = Some(timeout);
+        self
+    }
+    /// Sets the database returned by [`ArcadeDB::default_db`].
+    pub fn database(mut self, name: impl Into<String>) -> ArcadeDBBuilder {
+        self.0.database = Some(name.into());
         self
     }
     pub async fn build(mut self, url: impl Into<String>) -> Result<ArcadeDB> {
@@ -73,4 +301,45 @@ impl ArcadeDBBuilder {
 
         ArcadeDB::connect(self.0).await
     }
+    /// Connects to the url set by [`from_url`](ArcadeDBBuilder::from_url) or
+    /// [`from_env`](ArcadeDBBuilder::from_env), `http://localhost:2480` by default.
+    pub async fn connect(self) -> Result<ArcadeDB> {
+        ArcadeDB::connect(self.0).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{transport::mock::MockServer, ArcadeDB, Auth, ServerVersion};
+
+    #[tokio::test]
+    async fn should_negotiate_basic_capabilities_for_other_users() {
+        let server = MockServer::start(vec![
+            (403, r#"{ "error": "Forbidden" }"#),
+            (
+                200,
+                r#"{ "user": "reader", "version": "23.9.1 (build 5c5ad5b4)", "serverName": "ArcadeDB_0" }"#,
+            ),
+        ])
+        .await;
+        let arcadedb = ArcadeDB::builder()
+            .auth(Auth::basic("reader", "secret"))
+            .build(server.url.clone())
+            .await
+            .unwrap();
+
+        let capabilities = arcadedb.negotiate().await.unwrap();
+
+        assert_eq!(ServerVersion::new(23, 9, 1), capabilities.version);
+        assert_eq!(Some("ArcadeDB_0"), capabilities.server_name.as_deref());
+        assert_eq!(None, capabilities.clustered);
+        assert_eq!(
+            vec!["/api/v1/server?mode=default", "/api/v1/server?mode=basic"],
+            server
+                .requests()
+                .iter()
+                .map(|request| request.split(' ').nth(1).unwrap())
+                .collect::<Vec<_>>()
+        );
+    }
 }
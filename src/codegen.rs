@@ -0,0 +1,519 @@
+//! Generation of Rust structs from a database [`Schema`].
+//!
+//! ```rust,no_run
+//! use arcadedb_rs::{codegen, ArcadeDB, Auth};
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let arcadedb = ArcadeDB::builder()
+//!     .auth(Auth::basic("root", "playwithdata"))
+//!     .build("http://localhost:2480")
+//!     .await?;
+//!
+//! let schema = arcadedb.db("movies").schema().await?;
+//!
+//! std::fs::write("src/model.rs", codegen::generate(&schema))?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The same generator is available from the command line with the `cli` feature:
+//! `arcadedb codegen movies --output src/model.rs`, connecting like
+//! [`ArcadeDBOptions::from_env`](crate::ArcadeDBOptions::from_env) unless `--url` is given.
+//!
+//! `BINARY` properties become `Vec<u8>` read through [`convert::binary`](crate::convert::binary).
+//! `DECIMAL` properties become `serde_json::Number`, or `rust_decimal::Decimal` with
+//! [`DecimalType::RustDecimal`], see [`generate_with`]. Lists and maps of binaries, and of
+//! decimals with `rust_decimal`, hold `serde_json::Value`s.
+//!
+//! Types whose names map to the same struct name get a numeric suffix, e.g. `ActedIn2`.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+};
+
+use crate::schema::{PropertyInfo, PropertyType, Schema, TypeInfo, TypeKind};
+
+/// Strict and reserved keywords, usable as raw identifiers.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Keywords that cannot be raw identifiers.
+const NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "super", "Self"];
+
+/// Names imported by the generated code, which structs must not shadow.
+const IMPORTS: &[&str] = &["HashMap", "RecordID", "Deserialize", "Serialize"];
+
+/// How [`generate_with`] maps `DECIMAL` properties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecimalType {
+    /// `serde_json::Number`, which needs no other dependency but reads decimals with
+    /// the precision of an `f64` unless serde_json's `arbitrary_precision` is enabled.
+    #[default]
+    Number,
+    /// `rust_decimal::Decimal`, which needs the `rust_decimal` feature of arcadedb-rs
+    /// in the crate using the generated code.
+    RustDecimal,
+}
+
+/// Options of [`generate_with`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    decimal: DecimalType,
+}
+
+impl Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn decimal(mut self, decimal: DecimalType) -> Self {
+        self.decimal = decimal;
+        self
+    }
+}
+
+/// A Rust type and the `#[serde(with = "...")]` module it needs, if any.
+struct RustType {
+    name: String,
+    with: Option<&'static str>,
+}
+
+impl RustType {
+    fn new(name: impl Into<String>) -> Self {
+        RustType {
+            name: name.into(),
+            with: None,
+        }
+    }
+}
+
+/// Generates one struct per type of `schema`, including inherited properties, with the
+/// default [`Options`].
+///
+/// Types whose name starts with `_`, like the `_migrations` type used by
+/// [`Migrator`](crate::migrations::Migrator), are skipped. Properties are wrapped in
+/// `Option` unless they are both mandatory and not null.
+pub fn generate(schema: &Schema) -> String {
+    generate_with(schema, &Options::default())
+}
+
+/// Generates the structs of `schema` like [`generate`], with `options`.
+pub fn generate_with(schema: &Schema, options: &Options) -> String {
+    let types = schema
+        .types
+        .iter()
+        .filter(|ty| !ty.name.starts_with('_'))
+        .collect::<Vec<_>>();
+
+    let mut used = IMPORTS
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<HashSet<_>>();
+    let structs = types
+        .iter()
+        .map(|ty| {
+            let name = unique(struct_name(&ty.name), "", &mut used);
+            (ty.name.as_str(), name)
+        })
+        .collect();
+    let generator = Generator {
+        schema,
+        options,
+        structs,
+    };
+
+    let mut body = String::new();
+    for ty in &types {
+        body.push('\n');
+        generator.generate_type(ty, &mut body);
+    }
+
+    let mut output = String::from("// Generated by arcadedb-rs codegen. Do not edit.\n\n");
+    if body.contains("HashMap<") {
+        output.push_str("use std::collections::HashMap;\n\n");
+    }
+    output.push_str("use arcadedb_rs::RecordID;\nuse serde::{Deserialize, Serialize};\n");
+    output.push_str(&body);
+    output
+}
+
+/// Appends a numeric suffix to `name` until it does not collide with the `used` names.
+fn unique(name: String, separator: &str, used: &mut HashSet<String>) -> String {
+    let mut candidate = name.clone();
+    let mut suffix = 2;
+    while used.contains(&candidate) {
+        candidate = format!("{}{}{}", name.trim_start_matches("r#"), separator, suffix);
+        suffix += 1;
+    }
+    used.insert(candidate.clone());
+    candidate
+}
+
+struct Generator<'a> {
+    schema: &'a Schema,
+    options: &'a Options,
+    /// The struct name of every generated type.
+    structs: HashMap<&'a str, String>,
+}
+
+impl Generator<'_> {
+    fn generate_type(&self, ty: &TypeInfo, out: &mut String) {
+        let _ = writeln!(out, "#[derive(Debug, Serialize, Deserialize)]");
+        let _ = writeln!(out, "pub struct {} {{", self.struct_name(&ty.name));
+        match ty.kind {
+            TypeKind::Vertex | TypeKind::Edge => {
+                let _ = writeln!(out, "    #[serde(rename = \"@rid\")]");
+                let _ = writeln!(out, "    pub rid: RecordID,");
+            }
+            TypeKind::Document => {
+                let _ = writeln!(
+                    out,
+                    "    #[serde(rename = \"@rid\", default, skip_serializing_if = \"Option::is_none\")]"
+                );
+                let _ = writeln!(out, "    pub rid: Option<RecordID>,");
+            }
+        }
+        // Properties are renamed rather than shadowing the generated record fields.
+        let mut fields = HashSet::from([String::from("rid")]);
+        if ty.kind == TypeKind::Edge {
+            let _ = writeln!(out, "    #[serde(rename = \"@out\")]");
+            let _ = writeln!(out, "    pub out: RecordID,");
+            let _ = writeln!(out, "    #[serde(rename = \"@in\")]");
+            let _ = writeln!(out, "    pub in_: RecordID,");
+            fields.extend([String::from("out"), String::from("in_")]);
+        }
+        for property in self.schema.properties(&ty.name) {
+            let field = unique(field_name(&property.name), "_", &mut fields);
+            self.generate_property(property, &field, out);
+        }
+        let _ = writeln!(out, "}}");
+    }
+
+    fn generate_property(&self, property: &PropertyInfo, field: &str, out: &mut String) {
+        let required = property.mandatory && property.not_null;
+        let rust_type = self.rust_type(property);
+
+        let mut attributes = vec![];
+        if field.trim_start_matches("r#") != property.name {
+            attributes.push(format!("rename = {:?}", property.name));
+        }
+        if !required {
+            attributes.push(String::from("default"));
+        }
+        match (rust_type.with, required) {
+            (Some(with), true) => attributes.push(format!("with = {:?}", with)),
+            (Some(with), false) => attributes.push(format!("with = \"{}::option\"", with)),
+            (None, _) => {}
+        }
+        if !required {
+            attributes.push(String::from("skip_serializing_if = \"Option::is_none\""));
+        }
+        if !attributes.is_empty() {
+            let _ = writeln!(out, "    #[serde({})]", attributes.join(", "));
+        }
+        if required {
+            let _ = writeln!(out, "    pub {}: {},", field, rust_type.name);
+        } else {
+            let _ = writeln!(out, "    pub {}: Option<{}>,", field, rust_type.name);
+        }
+    }
+
+    fn rust_type(&self, property: &PropertyInfo) -> RustType {
+        match property.property_type.parse::<PropertyType>() {
+            Ok(PropertyType::List) => RustType::new(format!("Vec<{}>", self.of_type(property))),
+            Ok(PropertyType::Map) => {
+                RustType::new(format!("HashMap<String, {}>", self.of_type(property)))
+            }
+            Ok(PropertyType::Embedded) => match &property.of_type {
+                Some(of_type) if self.schema.get_type(of_type).is_some() => {
+                    RustType::new(self.struct_name(of_type))
+                }
+                _ => RustType::new("serde_json::Value"),
+            },
+            Ok(property_type) => self.scalar_type(property_type),
+            Err(_) => RustType::new("serde_json::Value"),
+        }
+    }
+
+    /// The item type of a `LIST` or `MAP`: documents are embedded, vertices and edges are
+    /// linked.
+    fn of_type(&self, property: &PropertyInfo) -> String {
+        let of_type = match &property.of_type {
+            Some(of_type) => of_type,
+            None => return String::from("serde_json::Value"),
+        };
+        match self.schema.get_type(of_type) {
+            Some(ty) if ty.kind == TypeKind::Document => self.struct_name(&ty.name),
+            Some(_) => String::from("RecordID"),
+            None => match of_type.parse::<PropertyType>() {
+                Ok(PropertyType::List | PropertyType::Map | PropertyType::Embedded) | Err(_) => {
+                    String::from("serde_json::Value")
+                }
+                Ok(property_type) => match self.scalar_type(property_type) {
+                    RustType { with: None, name } => name,
+                    _ => String::from("serde_json::Value"),
+                },
+            },
+        }
+    }
+
+    fn scalar_type(&self, property_type: PropertyType) -> RustType {
+        match property_type {
+            PropertyType::Boolean => RustType::new("bool"),
+            PropertyType::Byte => RustType::new("i8"),
+            PropertyType::Short => RustType::new("i16"),
+            PropertyType::Integer => RustType::new("i32"),
+            PropertyType::Long => RustType::new("i64"),
+            PropertyType::Float => RustType::new("f32"),
+            PropertyType::Double => RustType::new("f64"),
+            PropertyType::Decimal => match self.options.decimal {
+                DecimalType::Number => RustType::new("serde_json::Number"),
+                DecimalType::RustDecimal => RustType {
+                    name: String::from("rust_decimal::Decimal"),
+                    with: Some("arcadedb_rs::convert::rust_decimal::decimal"),
+                },
+            },
+            PropertyType::String => RustType::new("String"),
+            PropertyType::Binary => RustType {
+                name: String::from("Vec<u8>"),
+                with: Some("arcadedb_rs::convert::binary"),
+            },
+            PropertyType::Date | PropertyType::Datetime => RustType::new("i64"),
+            PropertyType::Link => RustType::new("RecordID"),
+            PropertyType::Embedded | PropertyType::List | PropertyType::Map => {
+                RustType::new("serde_json::Value")
+            }
+        }
+    }
+
+    fn struct_name(&self, type_name: &str) -> String {
+        self.structs
+            .get(type_name)
+            .cloned()
+            .unwrap_or_else(|| struct_name(type_name))
+    }
+}
+
+fn words(name: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut current = String::new();
+    let chars = name.chars().collect::<Vec<_>>();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        let boundary = c.is_uppercase()
+            && i > 0
+            && (chars[i - 1].is_lowercase()
+                || chars[i - 1].is_numeric()
+                || chars.get(i + 1).is_some_and(|next| next.is_lowercase())
+                    && chars[i - 1].is_uppercase());
+        if boundary && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn struct_name(name: &str) -> String {
+    let name = words(name)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first
+                    .to_uppercase()
+                    .chain(chars.flat_map(char::to_lowercase))
+                    .collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<String>();
+    if name.starts_with(|c: char| c.is_numeric()) || name.is_empty() {
+        format!("Type{}", name)
+    } else if NON_RAW_KEYWORDS.contains(&name.as_str()) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+fn field_name(name: &str) -> String {
+    let name = words(name)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_");
+    if name.is_empty() || name.starts_with(|c: char| c.is_numeric()) {
+        format!("field_{}", name)
+    } else if NON_RAW_KEYWORDS.contains(&name.as_str()) {
+        format!("{}_", name)
+    } else if KEYWORDS.contains(&name.as_str()) {
+        format!("r#{}", name)
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::schema::Schema;
+
+    use super::{field_name, generate, generate_with, struct_name, DecimalType, Options};
+
+    #[test]
+    fn should_convert_names() {
+        assert_eq!("ActedIn", struct_name("ACTED_IN"));
+        assert_eq!("Movie", struct_name("Movie"));
+        assert_eq!("HttpRequest", struct_name("HTTPRequest"));
+        assert_eq!("released_at", field_name("releasedAt"));
+        assert_eq!("r#type", field_name("type"));
+        assert_eq!("field_1st", field_name("1st"));
+        assert_eq!("crate_", field_name("crate"));
+        assert_eq!("self_", field_name("Self"));
+        assert_eq!("r#try", field_name("try"));
+        assert_eq!("r#abstract", field_name("abstract"));
+        assert_eq!("Self_", struct_name("self"));
+    }
+
+    /// The fixture is compiled by `tests/codegen.rs`.
+    #[test]
+    fn should_generate_compiling_fixture() {
+        let schema = Schema {
+            database: serde_json::from_value(json!({ "name": "movies" })).unwrap(),
+            types: serde_json::from_str(include_str!("../tests/codegen/types.json")).unwrap(),
+            indexes: vec![],
+            buckets: vec![],
+        };
+
+        assert_eq!(include_str!("../tests/codegen/model.rs"), generate(&schema));
+    }
+
+    #[test]
+    fn should_not_map_decimals_to_floats() {
+        let schema = Schema {
+            database: serde_json::from_value(json!({ "name": "shop" })).unwrap(),
+            types: serde_json::from_value(json!([{
+                "name": "Invoice",
+                "type": "document",
+                "properties": [{ "name": "total", "type": "DECIMAL" }]
+            }]))
+            .unwrap(),
+            indexes: vec![],
+            buckets: vec![],
+        };
+
+        let code = generate(&schema);
+        assert!(!code.contains("f64"));
+        assert!(code.contains("pub total: Option<serde_json::Number>,"));
+
+        let code = generate_with(&schema, &Options::new().decimal(DecimalType::RustDecimal));
+        assert!(code.contains("with = \"arcadedb_rs::convert::rust_decimal::decimal::option\""));
+        assert!(code.contains("pub total: Option<rust_decimal::Decimal>,"));
+    }
+
+    #[test]
+    fn should_dedupe_struct_names() {
+        let schema = Schema {
+            database: serde_json::from_value(json!({ "name": "movies" })).unwrap(),
+            types: serde_json::from_value(json!([
+                { "name": "ACTED_IN", "type": "edge" },
+                { "name": "acted_in", "type": "edge" },
+                { "name": "serialize", "type": "document" },
+                {
+                    "name": "Credits",
+                    "type": "document",
+                    "properties": [{ "name": "role", "type": "EMBEDDED", "ofType": "acted_in" }]
+                }
+            ]))
+            .unwrap(),
+            indexes: vec![],
+            buckets: vec![],
+        };
+
+        let code = generate(&schema);
+
+        assert!(code.contains("pub struct ActedIn {"));
+        assert!(code.contains("pub struct ActedIn2 {"));
+        assert!(code.contains("pub struct Serialize2 {"));
+        assert!(code.contains("pub role: Option<ActedIn2>,"));
+    }
+
+    #[test]
+    fn should_generate_structs() {
+        let schema = Schema {
+            database: serde_json::from_value(json!({ "name": "movies" })).unwrap(),
+            types: serde_json::from_value(json!([
+                {
+                    "name": "Movie",
+                    "type": "vertex",
+                    "properties": [
+                        { "name": "title", "type": "STRING", "mandatory": true, "notNull": true },
+                        { "name": "released", "type": "INTEGER" },
+                        { "name": "tags", "type": "LIST", "ofType": "STRING" },
+                        { "name": "director", "type": "LINK" }
+                    ]
+                },
+                {
+                    "name": "ACTED_IN",
+                    "type": "edge",
+                    "properties": [{ "name": "roles", "type": "MAP", "ofType": "STRING" }]
+                },
+                { "name": "_migrations", "type": "document" }
+            ]))
+            .unwrap(),
+            indexes: vec![],
+            buckets: vec![],
+        };
+
+        assert_eq!(
+            r#"// Generated by arcadedb-rs codegen. Do not edit.
+
+use std::collections::HashMap;
+
+use arcadedb_rs::RecordID;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Movie {
+    #[serde(rename = "@rid")]
+    pub rid: RecordID,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub released: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub director: Option<RecordID>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ActedIn {
+    #[serde(rename = "@rid")]
+    pub rid: RecordID,
+    #[serde(rename = "@out")]
+    pub out: RecordID,
+    #[serde(rename = "@in")]
+    pub in_: RecordID,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roles: Option<HashMap<String, String>>,
+}
+"#,
+            generate(&schema)
+        );
+    }
+}
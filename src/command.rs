@@ -1,9 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 
-use crate::{db::Queryable, ArcadeDBError, ErrorResponse};
+use crate::{db::Queryable, options::REDACTED, ArcadeDBError, ErrorResponse};
 
 #[derive(Serialize)]
 pub struct Statement<'a, 'b, T: Queryable> {
@@ -14,6 +14,8 @@ pub struct Statement<'a, 'b, T: Queryable> {
     command: &'b str,
     language: Language,
     params: HashMap<&'b str, Value>,
+    #[serde(skip_serializing)]
+    sensitive: HashSet<&'b str>,
 }
 
 pub enum StatementKind {
@@ -21,11 +23,14 @@ pub enum StatementKind {
     Command,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum Language {
     SQL,
+    #[serde(rename = "sqlscript")]
+    SqlScript,
     Cypher,
+    Gremlin,
 }
 
 impl<'a, 'b, Q: Queryable + Send + Sync> Statement<'a, 'b, Q> {
@@ -34,6 +39,7 @@ impl<'a, 'b, Q: Queryable + Send + Sync> Statement<'a, 'b, Q> {
             queryable,
             command,
             params: HashMap::new(),
+            sensitive: HashSet::new(),
             language: Language::SQL,
             kind,
         }
@@ -44,6 +50,19 @@ impl<'a, 'b, Q: Queryable + Send + Sync> Statement<'a, 'b, Q> {
         self
     }
 
+    /// Binds a parameter whose value is redacted in `Debug` output and error messages.
+    /// Values shorter than five characters are only redacted from `Debug` output, since
+    /// replacing them in error messages would mangle unrelated text.
+    pub fn sensitive_param(self, name: &'b str, value: impl Into<Value>) -> Self {
+        self.param(name, value).sensitive(name)
+    }
+
+    /// Marks the parameter `name` as sensitive, see [`sensitive_param`](Statement::sensitive_param).
+    pub fn sensitive(mut self, name: &'b str) -> Self {
+        self.sensitive.insert(name);
+        self
+    }
+
     pub fn language(mut self, language: Language) -> Self {
         self.language = language;
         self
@@ -59,7 +78,44 @@ impl<'a, 'b, Q: Queryable + Send + Sync> Statement<'a, 'b, Q> {
     pub async fn send<T: DeserializeOwned + Send + Sync>(
         self,
     ) -> Result<Vec<T>, ArcadeDBError<ErrorResponse>> {
-        self.queryable.send(self).await
+        let secrets = self.secrets();
+        self.queryable
+            .send(self)
+            .await
+            .map_err(|err| err.redact(&secrets))
+    }
+
+    fn secrets(&self) -> Vec<String> {
+        self.sensitive
+            .iter()
+            .filter_map(|name| self.params.get(name))
+            .map(|value| match value {
+                Value::String(value) => value.clone(),
+                value => value.to_string(),
+            })
+            .filter(|secret| !secret.is_empty())
+            .collect()
+    }
+}
+
+impl<'a, 'b, Q: Queryable> std::fmt::Debug for Statement<'a, 'b, Q> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params = self
+            .params
+            .iter()
+            .map(|(name, value)| {
+                if self.sensitive.contains(name) {
+                    (*name, Value::String(REDACTED.to_string()))
+                } else {
+                    (*name, value.clone())
+                }
+            })
+            .collect::<HashMap<_, _>>();
+        f.debug_struct("Statement")
+            .field("command", &self.command)
+            .field("language", &self.language)
+            .field("params", &params)
+            .finish()
     }
 }
 
@@ -72,3 +128,81 @@ impl<T: Into<Value> + Clone> IntoValue for T {
         self.clone().into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeOwned;
+
+    use crate::{db::Queryable, ArcadeDBError, ErrorResponse};
+
+    use super::{Statement, StatementKind};
+
+    struct Offline;
+
+    #[async_trait::async_trait]
+    impl Queryable for Offline {
+        async fn send<'a, 'b, T: DeserializeOwned + Send + Sync, Q: Queryable + Send + Sync>(
+            &self,
+            _: Statement<'a, 'b, Q>,
+        ) -> Result<Vec<T>, ArcadeDBError<ErrorResponse>> {
+            unreachable!("statements are not sent in these tests")
+        }
+
+        fn name(&self) -> &str {
+            "offline"
+        }
+    }
+
+    #[test]
+    fn should_redact_sensitive_params() {
+        let statement = Statement::new(
+            &Offline,
+            "create user :name identified by :password",
+            StatementKind::Command,
+        )
+        .param("name", "keanu")
+        .sensitive_param("password", "playwithdata");
+        let debug = format!("{:?}", statement);
+
+        assert!(debug.contains("keanu"));
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains("playwithdata"));
+        assert_eq!(vec!["playwithdata".to_string()], statement.secrets());
+    }
+
+    #[test]
+    fn should_redact_error_messages() {
+        let err = ArcadeDBError::Error(ErrorResponse {
+            error: "Invalid password playwithdata".to_string(),
+            detail: Some("playwithdata is not valid".to_string()),
+            exception: None,
+            status: None,
+        })
+        .redact(&["playwithdata".to_string()]);
+
+        match err {
+            ArcadeDBError::Error(response) => {
+                assert_eq!("Invalid password <redacted>", response.error);
+                assert_eq!(Some("<redacted> is not valid"), response.detail.as_deref());
+            }
+            ArcadeDBError::Generic(_) => panic!("Expected an error response"),
+        }
+
+        let err = ArcadeDBError::<ErrorResponse>::Generic(anyhow::anyhow!("bad playwithdata"))
+            .redact(&["playwithdata".to_string()]);
+        assert_eq!("bad <redacted>", err.to_string());
+
+        let err = ArcadeDBError::<ErrorResponse>::Generic(anyhow::anyhow!(
+            "Invalid pin '12' for user12 in 2012, retry 12"
+        ))
+        .redact(&["12".to_string()]);
+        assert_eq!(
+            "Invalid pin '<redacted>' for user12 in 2012, retry <redacted>",
+            err.to_string()
+        );
+
+        let err = ArcadeDBError::<ErrorResponse>::Generic(anyhow::anyhow!("10 items"))
+            .redact(&["1".to_string()]);
+        assert_eq!("10 items", err.to_string());
+    }
+}
@@ -0,0 +1,75 @@
+//! `#[serde(with = "...")]` for `Vec<u8>` binaries, which the server sends and reads as
+//! base64 strings. Unlike the other modules it needs no cargo feature.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Deserializer, Serializer};
+
+pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&STANDARD.encode(value))
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let text = String::deserialize(deserializer)?;
+    STANDARD.decode(text).map_err(serde::de::Error::custom)
+}
+
+/// `#[serde(with = "...")]` for `Option<Vec<u8>>`.
+pub mod option {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => super::serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<u8>>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|text| STANDARD.decode(text).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Attachment {
+        #[serde(with = "crate::convert::binary")]
+        content: Vec<u8>,
+        #[serde(default, with = "crate::convert::binary::option")]
+        thumbnail: Option<Vec<u8>>,
+    }
+
+    #[test]
+    fn should_convert_binaries() {
+        let attachment = Attachment {
+            content: vec![1, 2, 3],
+            thumbnail: None,
+        };
+
+        assert_eq!(
+            json!({ "content": "AQID", "thumbnail": null }),
+            serde_json::to_value(&attachment).unwrap()
+        );
+        assert_eq!(
+            attachment,
+            serde_json::from_value(json!({ "content": "AQID" })).unwrap()
+        );
+        assert_eq!(
+            Some(vec![1, 2, 3]),
+            serde_json::from_value::<Attachment>(json!({ "content": "", "thumbnail": "AQID" }))
+                .unwrap()
+                .thumbnail
+        );
+    }
+}
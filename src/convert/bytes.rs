@@ -0,0 +1,58 @@
+//! Binaries are sent and read as base64 strings.
+
+use ::bytes::Bytes;
+
+use super::with::serde_with;
+use crate::ArcadeValue;
+
+serde_with!(
+    /// `#[serde(with = "...")]` for `Bytes`.
+    bytes,
+    ::bytes::Bytes
+);
+
+impl From<Bytes> for ArcadeValue {
+    fn from(value: Bytes) -> Self {
+        ArcadeValue::Binary(value.to_vec())
+    }
+}
+
+impl TryFrom<ArcadeValue> for Bytes {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ArcadeValue) -> Result<Self, Self::Error> {
+        value
+            .as_binary()
+            .map(Bytes::from)
+            .ok_or_else(|| anyhow::anyhow!("Expected a binary, found {:?}", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Attachment {
+        #[serde(with = "crate::convert::bytes::bytes")]
+        content: Bytes,
+    }
+
+    #[test]
+    fn should_convert_bytes() {
+        let content = Bytes::from_static(&[1, 2, 3]);
+
+        assert_eq!(
+            Attachment {
+                content: content.clone()
+            },
+            serde_json::from_value(json!({ "content": "AQID" })).unwrap()
+        );
+        assert_eq!(
+            json!({ "content": "AQID" }),
+            serde_json::to_value(Attachment { content }).unwrap()
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Dates are sent as epoch milliseconds and read from epoch milliseconds, RFC 3339
+//! strings or the default `yyyy-MM-dd HH:mm:ss` and `yyyy-MM-dd` formats.
+//! `DATE`s are UTC midnights, see [`convert`](super).
+
+use ::chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+use super::{epoch_millis, with::serde_with};
+use crate::ArcadeValue;
+
+serde_with!(
+    /// `#[serde(with = "...")]` for `DateTime<Utc>`.
+    datetime,
+    ::chrono::DateTime<::chrono::Utc>
+);
+serde_with!(
+    /// `#[serde(with = "...")]` for `NaiveDateTime`, read and written as UTC.
+    naive_datetime,
+    ::chrono::NaiveDateTime
+);
+serde_with!(
+    /// `#[serde(with = "...")]` for `NaiveDate`.
+    date,
+    ::chrono::NaiveDate
+);
+
+impl From<DateTime<Utc>> for ArcadeValue {
+    fn from(value: DateTime<Utc>) -> Self {
+        let nanos =
+            value.timestamp() as i128 * 1_000_000_000 + value.timestamp_subsec_nanos() as i128;
+        ArcadeValue::DateTime(epoch_millis(nanos))
+    }
+}
+
+impl From<NaiveDateTime> for ArcadeValue {
+    fn from(value: NaiveDateTime) -> Self {
+        value.and_utc().into()
+    }
+}
+
+impl From<NaiveDate> for ArcadeValue {
+    fn from(value: NaiveDate) -> Self {
+        ArcadeValue::Date(epoch_millis(
+            NaiveDateTime::from(value).and_utc().timestamp() as i128 * 1_000_000_000,
+        ))
+    }
+}
+
+impl TryFrom<ArcadeValue> for DateTime<Utc> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ArcadeValue) -> Result<Self, Self::Error> {
+        if let Some(millis) = value.as_date() {
+            return DateTime::from_timestamp_millis(millis)
+                .ok_or_else(|| anyhow::anyhow!("Invalid timestamp {}", millis));
+        }
+        let text = value
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Expected a date, found {:?}", value))?;
+        if let Ok(datetime) = DateTime::parse_from_rfc3339(text) {
+            return Ok(datetime.with_timezone(&Utc));
+        }
+        NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f")
+            .or_else(|_| NaiveDate::parse_from_str(text, "%Y-%m-%d").map(NaiveDateTime::from))
+            .map(|datetime| datetime.and_utc())
+            .map_err(|_| anyhow::anyhow!("Invalid date {:?}", text))
+    }
+}
+
+impl TryFrom<ArcadeValue> for NaiveDateTime {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ArcadeValue) -> Result<Self, Self::Error> {
+        DateTime::<Utc>::try_from(value).map(|datetime| datetime.naive_utc())
+    }
+}
+
+impl TryFrom<ArcadeValue> for NaiveDate {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ArcadeValue) -> Result<Self, Self::Error> {
+        DateTime::<Utc>::try_from(value).map(|datetime| datetime.date_naive())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, NaiveDate, Utc};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::ArcadeValue;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Order {
+        #[serde(with = "crate::convert::chrono::datetime")]
+        created: DateTime<Utc>,
+        #[serde(with = "crate::convert::chrono::date")]
+        due: NaiveDate,
+    }
+
+    #[test]
+    fn should_convert_chrono_dates() {
+        let due = NaiveDate::from_ymd_opt(2023, 9, 25).unwrap();
+        let created = due.and_hms_opt(10, 30, 0).unwrap().and_utc();
+
+        assert_eq!(ArcadeValue::Date(1695600000000), ArcadeValue::from(due));
+        assert_eq!(
+            Order { created, due },
+            serde_json::from_value(json!({ "created": 1695637800000_i64, "due": "2023-09-25" }))
+                .unwrap()
+        );
+        assert_eq!(
+            Order { created, due },
+            serde_json::from_value(json!({
+                "created": "2023-09-25 10:30:00",
+                "due": 1695600000000_i64
+            }))
+            .unwrap()
+        );
+        assert_eq!(
+            json!({ "created": 1695637800000_i64, "due": 1695600000000_i64 }),
+            serde_json::to_value(Order { created, due }).unwrap()
+        );
+        assert!(DateTime::<Utc>::try_from(ArcadeValue::from("yesterday")).is_err());
+    }
+
+    #[test]
+    fn should_round_pre_epoch_datetimes_down() {
+        let before = NaiveDate::from_ymd_opt(1969, 12, 31)
+            .unwrap()
+            .and_hms_micro_opt(23, 59, 59, 999_500)
+            .unwrap()
+            .and_utc();
+
+        assert_eq!(ArcadeValue::DateTime(-1), ArcadeValue::from(before));
+        assert_eq!(
+            ArcadeValue::Date(-86400000),
+            ArcadeValue::from(before.date_naive())
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Conversions between [`ArcadeValue`] and the types of optional third-party crates,
+//! each behind the cargo feature of the same name: `chrono`, `time`, `uuid`,
+//! `rust_decimal` and `bytes`.
+//!
+//! Every type converts into an [`ArcadeValue`], which can be bound with
+//! `Statement::param`, and back from an [`ArcadeValue`] with `TryFrom`. The modules
+//! also provide `serialize`/`deserialize` pairs for `#[serde(with = "...")]` that
+//! accept the forms returned by the server, e.g. dates as epoch milliseconds or as
+//! formatted strings, with an `option` submodule for optional fields. The [`binary`]
+//! module, always available, works the same way:
+//!
+//! ```rust
+//! use arcadedb_rs::convert;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Attachment {
+//!     #[serde(with = "convert::binary")]
+//!     content: Vec<u8>,
+//!     #[serde(default, with = "convert::binary::option")]
+//!     thumbnail: Option<Vec<u8>>,
+//! }
+//!
+//! let attachment: Attachment = serde_json::from_str(r#"{ "content": "AQID" }"#).unwrap();
+//! assert_eq!(vec![1, 2, 3], attachment.content);
+//! assert_eq!(None, attachment.thumbnail);
+//! ```
+//!
+//! With the `chrono` feature, `#[serde(with = "convert::chrono::datetime")]` reads a
+//! `DateTime<Utc>` the same way.
+//!
+//! An ArcadeDB `DATE` is treated as midnight UTC by the `chrono` and `time` conversions:
+//! dates are written as the epoch milliseconds of their UTC midnight, and read back as the
+//! UTC day of the timestamp, whatever the time zone configured on the server. Datetimes
+//! before 1970 are rounded down to the millisecond, like Java's `Instant::toEpochMilli`.
+//!
+//! [`ArcadeValue`]: crate::ArcadeValue
+
+pub mod binary;
+#[cfg(feature = "bytes")]
+pub mod bytes;
+#[cfg(feature = "chrono")]
+pub mod chrono;
+#[cfg(feature = "rust_decimal")]
+pub mod rust_decimal;
+#[cfg(feature = "time")]
+pub mod time;
+#[cfg(feature = "uuid")]
+pub mod uuid;
+
+/// The epoch milliseconds of a timestamp in epoch nanoseconds, rounded down.
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn epoch_millis(nanos: i128) -> i64 {
+    nanos.div_euclid(1_000_000) as i64
+}
+
+#[cfg(any(
+    feature = "bytes",
+    feature = "chrono",
+    feature = "rust_decimal",
+    feature = "time",
+    feature = "uuid"
+))]
+mod with {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::ArcadeValue;
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Clone + Into<ArcadeValue>,
+        S: Serializer,
+    {
+        value.clone().into().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TryFrom<ArcadeValue, Error = anyhow::Error>,
+        D: Deserializer<'de>,
+    {
+        ArcadeValue::deserialize(deserializer)
+            .and_then(|value| T::try_from(value).map_err(serde::de::Error::custom))
+    }
+
+    pub fn deserialize_option<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: TryFrom<ArcadeValue, Error = anyhow::Error>,
+        D: Deserializer<'de>,
+    {
+        Option::<ArcadeValue>::deserialize(deserializer)?
+            .map(|value| T::try_from(value).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+
+    /// Generates a module with the `serialize`/`deserialize` pair of `$ty`, and an
+    /// `option` submodule for `Option<$ty>`.
+    macro_rules! serde_with {
+        ($(#[$meta:meta])* $name:ident, $ty:ty) => {
+            $(#[$meta])*
+            pub mod $name {
+                use serde::{Deserializer, Serializer};
+
+                pub fn serialize<S: Serializer>(
+                    value: &$ty,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    $crate::convert::with::serialize(value, serializer)
+                }
+
+                pub fn deserialize<'de, D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<$ty, D::Error> {
+                    $crate::convert::with::deserialize(deserializer)
+                }
+
+                /// The same for `Option`.
+                pub mod option {
+                    use serde::{Deserializer, Serializer};
+
+                    pub fn serialize<S: Serializer>(
+                        value: &Option<$ty>,
+                        serializer: S,
+                    ) -> Result<S::Ok, S::Error> {
+                        match value {
+                            Some(value) => super::serialize(value, serializer),
+                            None => serializer.serialize_none(),
+                        }
+                    }
+
+                    pub fn deserialize<'de, D: Deserializer<'de>>(
+                        deserializer: D,
+                    ) -> Result<Option<$ty>, D::Error> {
+                        $crate::convert::with::deserialize_option(deserializer)
+                    }
+                }
+            }
+        };
+    }
+    pub(crate) use serde_with;
+}
@@ -0,0 +1,101 @@
+//! Decimals are sent as strings so that they keep their precision, and read from
+//! strings or numbers.
+//!
+//! The server returns `DECIMAL` properties as JSON numbers, which are parsed as `f64`
+//! unless they are integers: fractional values beyond 15 to 17 significant digits lose
+//! precision on read, unless the `arbitrary_precision` feature is enabled. Values stored
+//! or projected as strings, e.g. with `select total.asString() as total`, are read exactly.
+
+use std::str::FromStr;
+
+use ::rust_decimal::Decimal;
+
+use super::with::serde_with;
+use crate::ArcadeValue;
+
+serde_with!(
+    /// `#[serde(with = "...")]` for `Decimal`.
+    decimal,
+    ::rust_decimal::Decimal
+);
+
+impl From<Decimal> for ArcadeValue {
+    fn from(value: Decimal) -> Self {
+        ArcadeValue::Decimal(value.to_string())
+    }
+}
+
+impl TryFrom<ArcadeValue> for Decimal {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ArcadeValue) -> Result<Self, Self::Error> {
+        let text = value
+            .as_decimal()
+            .ok_or_else(|| anyhow::anyhow!("Expected a decimal, found {:?}", value))?;
+        Decimal::from_str(&text)
+            .or_else(|_| Decimal::from_scientific(&text))
+            .map_err(|_| anyhow::anyhow!("Invalid decimal {:?}", text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::ArcadeValue;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Invoice {
+        #[serde(with = "crate::convert::rust_decimal::decimal")]
+        total: Decimal,
+    }
+
+    #[test]
+    fn should_convert_decimals() {
+        let total = Decimal::new(1234567890123456789, 2);
+
+        assert_eq!(
+            ArcadeValue::Decimal("12345678901234567.89".to_string()),
+            ArcadeValue::from(total)
+        );
+        assert_eq!(
+            Invoice { total },
+            serde_json::from_value(json!({ "total": "12345678901234567.89" })).unwrap()
+        );
+        assert_eq!(
+            Invoice {
+                total: Decimal::new(125, 1)
+            },
+            serde_json::from_value(json!({ "total": 12.5 })).unwrap()
+        );
+        assert_eq!(
+            json!({ "total": "12345678901234567.89" }),
+            serde_json::to_value(Invoice { total }).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_read_twenty_digits() {
+        assert_eq!(
+            Invoice {
+                total: "123456789012345678.90".parse().unwrap()
+            },
+            serde_json::from_value(json!({ "total": "123456789012345678.90" })).unwrap()
+        );
+        assert_eq!(
+            Invoice {
+                total: Decimal::from(12345678901234567890u64)
+            },
+            serde_json::from_value(json!({ "total": 12345678901234567890u64 })).unwrap()
+        );
+        // Fractional numbers go through f64 and keep only its precision, unless the
+        // original text is kept.
+        let read: Invoice = serde_json::from_str(r#"{ "total": 1234567890123456.7891 }"#).unwrap();
+        assert_eq!(
+            cfg!(feature = "arbitrary_precision"),
+            read.total.to_string() == "1234567890123456.7891"
+        );
+    }
+}
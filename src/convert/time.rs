@@ -0,0 +1,146 @@
+//! Dates are sent as epoch milliseconds and read from epoch milliseconds, RFC 3339
+//! strings or the default `yyyy-MM-dd HH:mm:ss` and `yyyy-MM-dd` formats.
+//! `DATE`s are UTC midnights, see [`convert`](super).
+
+use ::time::{
+    format_description::{well_known::Rfc3339, FormatItem},
+    macros::format_description,
+    Date, OffsetDateTime, PrimitiveDateTime,
+};
+
+use super::{epoch_millis, with::serde_with};
+use crate::ArcadeValue;
+
+const DATE_TIME: &[FormatItem<'_>] =
+    format_description!("[year]-[month]-[day] [hour]:[minute]:[second][optional [.[subsecond]]]");
+const DATE: &[FormatItem<'_>] = format_description!("[year]-[month]-[day]");
+
+serde_with!(
+    /// `#[serde(with = "...")]` for `OffsetDateTime`.
+    datetime,
+    ::time::OffsetDateTime
+);
+serde_with!(
+    /// `#[serde(with = "...")]` for `PrimitiveDateTime`, read and written as UTC.
+    primitive_datetime,
+    ::time::PrimitiveDateTime
+);
+serde_with!(
+    /// `#[serde(with = "...")]` for `Date`.
+    date,
+    ::time::Date
+);
+
+impl From<OffsetDateTime> for ArcadeValue {
+    fn from(value: OffsetDateTime) -> Self {
+        ArcadeValue::DateTime(epoch_millis(value.unix_timestamp_nanos()))
+    }
+}
+
+impl From<PrimitiveDateTime> for ArcadeValue {
+    fn from(value: PrimitiveDateTime) -> Self {
+        value.assume_utc().into()
+    }
+}
+
+impl From<Date> for ArcadeValue {
+    fn from(value: Date) -> Self {
+        let midnight = value.midnight().assume_utc();
+        ArcadeValue::Date(epoch_millis(midnight.unix_timestamp_nanos()))
+    }
+}
+
+impl TryFrom<ArcadeValue> for OffsetDateTime {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ArcadeValue) -> Result<Self, Self::Error> {
+        if let Some(millis) = value.as_date() {
+            return Ok(OffsetDateTime::from_unix_timestamp_nanos(
+                millis as i128 * 1_000_000,
+            )?);
+        }
+        let text = value
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Expected a date, found {:?}", value))?;
+        OffsetDateTime::parse(text, &Rfc3339)
+            .or_else(|_| {
+                PrimitiveDateTime::parse(text, DATE_TIME).map(|datetime| datetime.assume_utc())
+            })
+            .or_else(|_| Date::parse(text, DATE).map(|date| date.midnight().assume_utc()))
+            .map_err(|_| anyhow::anyhow!("Invalid date {:?}", text))
+    }
+}
+
+impl TryFrom<ArcadeValue> for PrimitiveDateTime {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ArcadeValue) -> Result<Self, Self::Error> {
+        OffsetDateTime::try_from(value).map(|datetime| {
+            let datetime = datetime.to_offset(::time::UtcOffset::UTC);
+            PrimitiveDateTime::new(datetime.date(), datetime.time())
+        })
+    }
+}
+
+impl TryFrom<ArcadeValue> for Date {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ArcadeValue) -> Result<Self, Self::Error> {
+        OffsetDateTime::try_from(value)
+            .map(|datetime| datetime.to_offset(::time::UtcOffset::UTC).date())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use time::{macros::datetime, Date, Month, OffsetDateTime};
+
+    use crate::ArcadeValue;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Order {
+        #[serde(with = "crate::convert::time::datetime")]
+        created: OffsetDateTime,
+        #[serde(with = "crate::convert::time::date")]
+        due: Date,
+    }
+
+    #[test]
+    fn should_convert_time_dates() {
+        let due = Date::from_calendar_date(2023, Month::September, 25).unwrap();
+        let created = datetime!(2023-09-25 10:30:00 UTC);
+
+        assert_eq!(ArcadeValue::Date(1695600000000), ArcadeValue::from(due));
+        assert_eq!(
+            Order { created, due },
+            serde_json::from_value(json!({ "created": 1695637800000_i64, "due": "2023-09-25" }))
+                .unwrap()
+        );
+        assert_eq!(
+            Order { created, due },
+            serde_json::from_value(json!({
+                "created": "2023-09-25T10:30:00Z",
+                "due": "2023-09-25 00:00:00"
+            }))
+            .unwrap()
+        );
+        assert_eq!(
+            json!({ "created": 1695637800000_i64, "due": 1695600000000_i64 }),
+            serde_json::to_value(Order { created, due }).unwrap()
+        );
+        assert!(OffsetDateTime::try_from(ArcadeValue::from("yesterday")).is_err());
+    }
+
+    #[test]
+    fn should_round_pre_epoch_datetimes_down() {
+        let before = datetime!(1969-12-31 23:59:59.9995 UTC);
+
+        assert_eq!(ArcadeValue::DateTime(-1), ArcadeValue::from(before));
+        assert_eq!(
+            ArcadeValue::Date(-86400000),
+            ArcadeValue::from(before.date())
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Uuids are sent and read as strings. `Uuid` also implements `Serialize` and
+//! `Deserialize` itself; the `uuid` helper reports invalid values like the other modules.
+
+use ::uuid::Uuid;
+
+use super::with::serde_with;
+use crate::ArcadeValue;
+
+serde_with!(
+    /// `#[serde(with = "...")]` for `Uuid`.
+    uuid,
+    ::uuid::Uuid
+);
+
+impl From<Uuid> for ArcadeValue {
+    fn from(value: Uuid) -> Self {
+        ArcadeValue::String(value.to_string())
+    }
+}
+
+impl TryFrom<ArcadeValue> for Uuid {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ArcadeValue) -> Result<Self, Self::Error> {
+        let text = value
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Expected a uuid, found {:?}", value))?;
+        Ok(Uuid::parse_str(text)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use crate::ArcadeValue;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Session {
+        #[serde(with = "crate::convert::uuid::uuid")]
+        id: Uuid,
+        #[serde(default, with = "crate::convert::uuid::uuid::option")]
+        parent: Option<Uuid>,
+        other: Uuid,
+    }
+
+    #[test]
+    fn should_convert_uuids() {
+        let uuid = Uuid::parse_str("7c9e6679-7425-40de-944b-e07fc1f90ae7").unwrap();
+
+        assert_eq!(
+            ArcadeValue::from("7c9e6679-7425-40de-944b-e07fc1f90ae7"),
+            ArcadeValue::from(uuid)
+        );
+        assert_eq!(uuid, Uuid::try_from(ArcadeValue::from(uuid)).unwrap());
+        assert!(Uuid::try_from(ArcadeValue::Integer(1)).is_err());
+    }
+
+    #[test]
+    fn should_serialize_uuids() {
+        let uuid = Uuid::parse_str("7c9e6679-7425-40de-944b-e07fc1f90ae7").unwrap();
+        let json = json!({
+            "id": "7c9e6679-7425-40de-944b-e07fc1f90ae7",
+            "parent": null,
+            "other": "7c9e6679-7425-40de-944b-e07fc1f90ae7"
+        });
+        let session = Session {
+            id: uuid,
+            parent: None,
+            other: uuid,
+        };
+
+        assert_eq!(session, serde_json::from_value(json.clone()).unwrap());
+        assert_eq!(json, serde_json::to_value(&session).unwrap());
+    }
+}
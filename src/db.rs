@@ -1,11 +1,23 @@
 use std::sync::Arc;
 
 use serde::de::DeserializeOwned;
+use serde_json::Value;
 
 use crate::{
+    admin::{
+        self, BackupSummary, CheckReport, Count, DatabaseStats, ExportFormat, ExportSummary,
+        ImportOptions, ImportSummary, PageCacheStats,
+    },
     command::{Statement, StatementKind},
     error::{ArcadeDBError, ErrorResponse},
-    protocol::{GenericResponse, QueryCommand, ServerCommand, ServerCommandRequest},
+    gremlin::Traversal,
+    protocol::{GenericResponse, QueryCommand, ServerCommand, ServerCommandRequest, ServerMode},
+    schema::{
+        self, AlterProperty, AlterType, CreateIndex, CreateProperty, CreateType, DatabaseInfo,
+        DropIndex, DropProperty, DropType, IndexInfo, IndexType, PropertyConstraint, PropertyType,
+        Schema, SchemaChange, SchemaDefinition, Setting, SyncMode, TypeAlteration, TypeKind,
+    },
+    sql::{ident, sql_value},
     transaction::Transaction,
     ArcadeDB,
 };
@@ -79,6 +91,15 @@ impl Database {
             .map(|response| response.payload)
     }
 
+    /// Opens the database on the server.
+    pub async fn open(&self) -> Result<GenericResponse, ArcadeDBError<ErrorResponse>> {
+        self.client.open_database(&self.name).await
+    }
+    /// Closes the database on the server, releasing its files and cached pages.
+    pub async fn close(&self) -> Result<GenericResponse, ArcadeDBError<ErrorResponse>> {
+        self.client.close_database(&self.name).await
+    }
+
     pub async fn tx(&self) -> Result<Transaction, ArcadeDBError<ErrorResponse>> {
         Transaction::begin(self.clone()).await
     }
@@ -89,8 +110,208 @@ impl Database {
     pub fn command<'a, 'b>(&'a self, stmt: &'b str) -> Statement<'a, 'b, Database> {
         Statement::new(self, stmt, StatementKind::Command)
     }
+    pub fn gremlin<'a, 'b>(&'a self, traversal: &'b Traversal) -> Statement<'a, 'b, Database> {
+        traversal.statement(self)
+    }
 
     pub fn name(&self) -> &str {
         self.name.as_ref()
     }
+
+    /// Starts a `CREATE TYPE` builder, executed with [`CreateType::send`].
+    pub fn create_type(&self, kind: TypeKind, name: impl Into<String>) -> CreateType {
+        CreateType::new(kind, name)
+    }
+    pub fn alter_type(&self, name: impl Into<String>, alteration: TypeAlteration) -> AlterType {
+        AlterType::new(name, alteration)
+    }
+    pub fn drop_type(&self, name: impl Into<String>) -> DropType {
+        DropType::new(name)
+    }
+
+    pub fn create_property(
+        &self,
+        type_name: impl Into<String>,
+        name: impl Into<String>,
+        property_type: PropertyType,
+    ) -> CreateProperty {
+        CreateProperty::new(type_name, name, property_type)
+    }
+    pub fn alter_property(
+        &self,
+        type_name: impl Into<String>,
+        name: impl Into<String>,
+        constraint: PropertyConstraint,
+    ) -> AlterProperty {
+        AlterProperty::new(type_name, name, constraint)
+    }
+    pub fn drop_property(
+        &self,
+        type_name: impl Into<String>,
+        name: impl Into<String>,
+    ) -> DropProperty {
+        DropProperty::new(type_name, name)
+    }
+
+    pub fn create_index<I, S>(
+        &self,
+        type_name: impl Into<String>,
+        properties: I,
+        index_type: IndexType,
+    ) -> CreateIndex
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        CreateIndex::new(type_name, properties, index_type)
+    }
+    pub fn drop_index(&self, name: impl Into<String>) -> DropIndex {
+        DropIndex::new(name)
+    }
+
+    pub async fn schema(&self) -> Result<Schema, ArcadeDBError<ErrorResponse>> {
+        Ok(Schema {
+            database: self.database_info().await?,
+            types: self.query("select from schema:types").send().await?,
+            indexes: self.indexes().await?,
+            buckets: self.query("select from schema:buckets").send().await?,
+        })
+    }
+    async fn database_info(&self) -> Result<DatabaseInfo, ArcadeDBError<ErrorResponse>> {
+        self.single("select from schema:database", "schema:database")
+            .await
+    }
+    pub async fn settings(&self) -> Result<Vec<Setting>, ArcadeDBError<ErrorResponse>> {
+        self.database_info().await.map(|info| info.settings)
+    }
+    /// Changes a database setting with `ALTER DATABASE`, e.g. `arcadedb.dateFormat`.
+    pub async fn set_setting(
+        &self,
+        key: &str,
+        value: impl Into<Value>,
+    ) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        let sql = format!(
+            "ALTER DATABASE {} {}",
+            ident(key)?,
+            sql_value(&value.into())
+        );
+        self.command(&sql).send::<Value>().await.map(|_| ())
+    }
+    pub async fn stats(&self) -> Result<DatabaseStats, ArcadeDBError<ErrorResponse>> {
+        let counts = |sql| async move {
+            self.query(sql).send::<Count>().await.map(|counts| {
+                counts
+                    .into_iter()
+                    .map(|count| (count.name, count.records))
+                    .collect()
+            })
+        };
+        let page_cache = match self.client.server_info(ServerMode::Default).await {
+            Ok(info) => Some(PageCacheStats::from_metrics(info.metrics.as_ref())),
+            Err(ArcadeDBError::Error(err)) if err.is_forbidden() => None,
+            Err(err) => return Err(err),
+        };
+        Ok(DatabaseStats {
+            types: counts("select name, records from schema:types").await?,
+            buckets: counts("select name, records from schema:buckets").await?,
+            operations: self
+                .single("select from schema:stats", "schema:stats")
+                .await?,
+            page_cache,
+        })
+    }
+    /// Computes the statements needed to move the live schema to `desired`.
+    pub async fn schema_diff(
+        &self,
+        desired: &SchemaDefinition,
+    ) -> Result<Vec<SchemaChange>, ArcadeDBError<ErrorResponse>> {
+        let live = self.schema().await?;
+        schema::diff(&live, desired)
+    }
+    /// Computes the statements needed to move the live schema to `desired` and,
+    /// with [`SyncMode::Apply`], executes them in order.
+    pub async fn schema_sync(
+        &self,
+        desired: &SchemaDefinition,
+        mode: SyncMode,
+    ) -> Result<Vec<SchemaChange>, ArcadeDBError<ErrorResponse>> {
+        let changes = self.schema_diff(desired).await?;
+        if mode == SyncMode::Apply {
+            for change in &changes {
+                self.command(&change.sql).send::<Value>().await?;
+            }
+        }
+        Ok(changes)
+    }
+    pub async fn indexes(&self) -> Result<Vec<IndexInfo>, ArcadeDBError<ErrorResponse>> {
+        self.query("select from schema:indexes").send().await
+    }
+    pub async fn rebuild_index(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        let sql = format!("REBUILD INDEX {}", ident(name.as_ref())?);
+        self.command(&sql).send::<Value>().await.map(|_| ())
+    }
+    pub async fn rebuild_indexes(&self) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        self.command("REBUILD INDEX *")
+            .send::<Value>()
+            .await
+            .map(|_| ())
+    }
+    /// Backs up the database to the server's default backup directory, or to `url_or_path`.
+    /// Like the other [`admin`] operations it reports no progress until it completes.
+    pub async fn backup(
+        &self,
+        url_or_path: Option<&str>,
+    ) -> Result<BackupSummary, ArcadeDBError<ErrorResponse>> {
+        let sql = admin::backup_sql(url_or_path)?;
+        self.single(&sql, "BACKUP DATABASE").await
+    }
+    /// Exports the database to `url_or_path` on the server, failing if the file exists.
+    /// Files must be absolute paths or `file:///` urls, see [`admin`].
+    pub async fn export(
+        &self,
+        format: ExportFormat,
+        url_or_path: &str,
+    ) -> Result<ExportSummary, ArcadeDBError<ErrorResponse>> {
+        let sql = admin::export_sql(format, url_or_path, false)?;
+        self.single(&sql, "EXPORT DATABASE").await
+    }
+    /// Exports the database to `url_or_path` on the server, overwriting an existing file.
+    /// Files must be absolute paths or `file:///` urls, see [`admin`].
+    pub async fn export_overwrite(
+        &self,
+        format: ExportFormat,
+        url_or_path: &str,
+    ) -> Result<ExportSummary, ArcadeDBError<ErrorResponse>> {
+        let sql = admin::export_sql(format, url_or_path, true)?;
+        self.single(&sql, "EXPORT DATABASE").await
+    }
+    /// Imports `url_or_path` into the database, from a `https://` url, or from an absolute
+    /// path or `file:///` url on the server, see [`admin`].
+    pub async fn import(
+        &self,
+        url_or_path: &str,
+        options: &ImportOptions,
+    ) -> Result<ImportSummary, ArcadeDBError<ErrorResponse>> {
+        let sql = admin::import_sql(url_or_path, options)?;
+        self.single(&sql, "IMPORT DATABASE").await
+    }
+    /// Checks the integrity of the database and, with `fix`, repairs what it can.
+    pub async fn check(&self, fix: bool) -> Result<CheckReport, ArcadeDBError<ErrorResponse>> {
+        self.single(&admin::check_sql(fix), "CHECK DATABASE").await
+    }
+    async fn single<T: DeserializeOwned + Send + Sync>(
+        &self,
+        sql: &str,
+        operation: &str,
+    ) -> Result<T, ArcadeDBError<ErrorResponse>> {
+        self.command(sql)
+            .send::<T>()
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("Missing {} result", operation).into())
+    }
 }
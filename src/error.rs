@@ -2,6 +2,8 @@ use std::fmt::Display;
 
 use serde::{de::DeserializeOwned, Deserialize};
 
+use crate::options::REDACTED;
+
 #[derive(Debug, thiserror::Error)]
 pub enum ArcadeDBError<T: DeserializeOwned + Display> {
     #[error("ArcadeDB error {}", .0)]
@@ -16,6 +18,67 @@ impl<T: DeserializeOwned + Display> From<anyhow::Error> for ArcadeDBError<T> {
     }
 }
 
+pub(crate) const MIN_REDACTED_LEN: usize = 5;
+
+impl ArcadeDBError<ErrorResponse> {
+    /// Replaces every occurrence of `secrets` in the error message. Secrets shorter than
+    /// [`MIN_REDACTED_LEN`] are only replaced where they are a whole token, e.g. a quoted
+    /// literal, since replacing values like `1` inside other words would mangle them.
+    pub(crate) fn redact(self, secrets: &[String]) -> Self {
+        let secrets = secrets
+            .iter()
+            .filter(|secret| !secret.is_empty())
+            .collect::<Vec<_>>();
+        if secrets.is_empty() {
+            return self;
+        }
+        let redact = |text: &str| {
+            secrets.iter().fold(text.to_string(), |text, secret| {
+                if secret.chars().count() >= MIN_REDACTED_LEN {
+                    text.replace(secret.as_str(), REDACTED)
+                } else {
+                    redact_tokens(&text, secret)
+                }
+            })
+        };
+        match self {
+            ArcadeDBError::Error(response) => ArcadeDBError::Error(ErrorResponse {
+                error: redact(&response.error),
+                detail: response.detail.as_deref().map(redact),
+                exception: response.exception,
+                status: response.status,
+            }),
+            ArcadeDBError::Generic(err) => {
+                let message = format!("{:#}", err);
+                let redacted = redact(&message);
+                if redacted != message {
+                    ArcadeDBError::Generic(anyhow::anyhow!(redacted))
+                } else {
+                    ArcadeDBError::Generic(err)
+                }
+            }
+        }
+    }
+}
+
+/// Replaces the occurrences of `secret` that are not surrounded by letters, digits or `_`.
+fn redact_tokens(text: &str, secret: &str) -> String {
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    let mut redacted = String::with_capacity(text.len());
+    let mut last = 0;
+    for (start, _) in text.match_indices(secret) {
+        let end = start + secret.len();
+        if is_word(text[..start].chars().next_back()) || is_word(text[end..].chars().next()) {
+            continue;
+        }
+        redacted.push_str(&text[last..start]);
+        redacted.push_str(REDACTED);
+        last = end;
+    }
+    redacted.push_str(&text[last..]);
+    redacted
+}
+
 impl From<ErrorResponse> for ArcadeDBError<ErrorResponse> {
     fn from(err: ErrorResponse) -> Self {
         ArcadeDBError::Error(err)
@@ -27,6 +90,16 @@ pub struct ErrorResponse {
     pub error: String,
     pub detail: Option<String>,
     pub exception: Option<String>,
+    /// The HTTP status of the response, e.g. 403 when the user is not allowed.
+    #[serde(default)]
+    pub status: Option<u16>,
+}
+
+impl ErrorResponse {
+    /// Returns `true` if the server denied the request to the authenticated user.
+    pub fn is_forbidden(&self) -> bool {
+        self.status == Some(403)
+    }
 }
 impl Display for ErrorResponse {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -35,3 +108,25 @@ impl Display for ErrorResponse {
             .finish()
     }
 }
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum InvalidIdentifier {
+    #[error("Identifier cannot be empty")]
+    Empty,
+    #[error("Identifier {0:?} contains invalid character {1:?}")]
+    InvalidCharacter(String, char),
+}
+
+impl<T: DeserializeOwned + Display> From<InvalidIdentifier> for ArcadeDBError<T> {
+    fn from(err: InvalidIdentifier) -> Self {
+        ArcadeDBError::Generic(err.into())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum InvalidRecordID {
+    #[error("Invalid RecordID, expected format #<bucket-identifier>:<record-position>")]
+    Format,
+    #[error(transparent)]
+    Number(#[from] std::num::ParseIntError),
+}
@@ -0,0 +1,376 @@
+use serde_json::Value;
+
+use crate::{
+    command::{Statement, StatementKind},
+    db::Queryable,
+    Language,
+};
+
+/// Entry point of the Gremlin traversal DSL.
+///
+/// ```rust
+/// use arcadedb_rs::g;
+///
+/// let traversal = g().V().has_label("Movie").out("ACTED_IN").values("name");
+///
+/// assert_eq!("g.V().hasLabel(p0).out(p1).values(p2)", traversal.gremlin());
+/// ```
+pub fn g() -> GraphTraversalSource {
+    GraphTraversalSource
+}
+
+pub struct GraphTraversalSource;
+
+#[allow(non_snake_case)]
+impl GraphTraversalSource {
+    pub fn V(self) -> Traversal {
+        Traversal::start().step("V", vec![])
+    }
+
+    pub fn V_ids(self, ids: impl IntoIterator<Item = impl Into<Value>>) -> Traversal {
+        Traversal::start().step("V", ids.into_iter().map(Into::into).collect())
+    }
+
+    pub fn E(self) -> Traversal {
+        Traversal::start().step("E", vec![])
+    }
+
+    pub fn E_ids(self, ids: impl IntoIterator<Item = impl Into<Value>>) -> Traversal {
+        Traversal::start().step("E", ids.into_iter().map(Into::into).collect())
+    }
+
+    pub fn add_v(self, label: impl Into<Value>) -> Traversal {
+        Traversal::start().mutating_step("addV", vec![label.into()])
+    }
+
+    pub fn add_e(self, label: impl Into<Value>) -> Traversal {
+        Traversal::start().mutating_step("addE", vec![label.into()])
+    }
+}
+
+/// A Gremlin traversal rendered as text, with every argument bound as a parameter.
+#[derive(Debug, Clone)]
+pub struct Traversal {
+    text: String,
+    bindings: Vec<(String, Value)>,
+    mutating: bool,
+}
+
+impl Traversal {
+    fn start() -> Self {
+        Traversal {
+            text: String::from("g"),
+            bindings: vec![],
+            mutating: false,
+        }
+    }
+
+    fn bind(&mut self, value: Value) -> String {
+        let name = format!("p{}", self.bindings.len());
+        self.bindings.push((name.clone(), value));
+        name
+    }
+
+    fn step(mut self, name: &str, args: Vec<Value>) -> Self {
+        let args = args
+            .into_iter()
+            .map(|arg| self.bind(arg))
+            .collect::<Vec<_>>();
+        self.text
+            .push_str(&format!(".{}({})", name, args.join(",")));
+        self
+    }
+
+    fn mutating_step(mut self, name: &str, args: Vec<Value>) -> Self {
+        self.mutating = true;
+        self.step(name, args)
+    }
+
+    fn predicate_step(mut self, name: &str, key: Value, predicate: P) -> Self {
+        let key = self.bind(key);
+        let args = predicate
+            .args
+            .into_iter()
+            .map(|arg| self.bind(arg))
+            .collect::<Vec<_>>();
+        self.text.push_str(&format!(
+            ".{}({},{}({}))",
+            name,
+            key,
+            predicate.name,
+            args.join(",")
+        ));
+        self
+    }
+
+    pub fn has_label(self, label: impl Into<Value>) -> Self {
+        self.step("hasLabel", vec![label.into()])
+    }
+
+    pub fn has_id(self, id: impl Into<Value>) -> Self {
+        self.step("hasId", vec![id.into()])
+    }
+
+    pub fn has_key(self, key: impl Into<Value>) -> Self {
+        self.step("has", vec![key.into()])
+    }
+
+    pub fn has(self, key: impl Into<Value>, value: impl Into<Value>) -> Self {
+        self.step("has", vec![key.into(), value.into()])
+    }
+
+    pub fn has_pred(self, key: impl Into<Value>, predicate: P) -> Self {
+        self.predicate_step("has", key.into(), predicate)
+    }
+
+    pub fn has_not(self, key: impl Into<Value>) -> Self {
+        self.step("hasNot", vec![key.into()])
+    }
+
+    pub fn out(self, label: impl Into<Value>) -> Self {
+        self.step("out", vec![label.into()])
+    }
+
+    pub fn in_(self, label: impl Into<Value>) -> Self {
+        self.step("in", vec![label.into()])
+    }
+
+    pub fn both(self, label: impl Into<Value>) -> Self {
+        self.step("both", vec![label.into()])
+    }
+
+    pub fn out_e(self, label: impl Into<Value>) -> Self {
+        self.step("outE", vec![label.into()])
+    }
+
+    pub fn in_e(self, label: impl Into<Value>) -> Self {
+        self.step("inE", vec![label.into()])
+    }
+
+    pub fn both_e(self, label: impl Into<Value>) -> Self {
+        self.step("bothE", vec![label.into()])
+    }
+
+    pub fn out_v(self) -> Self {
+        self.step("outV", vec![])
+    }
+
+    pub fn in_v(self) -> Self {
+        self.step("inV", vec![])
+    }
+
+    pub fn other_v(self) -> Self {
+        self.step("otherV", vec![])
+    }
+
+    pub fn values(self, key: impl Into<Value>) -> Self {
+        self.step("values", vec![key.into()])
+    }
+
+    pub fn value_map(self) -> Self {
+        self.step("valueMap", vec![])
+    }
+
+    pub fn element_map(self) -> Self {
+        self.step("elementMap", vec![])
+    }
+
+    pub fn id(self) -> Self {
+        self.step("id", vec![])
+    }
+
+    pub fn label(self) -> Self {
+        self.step("label", vec![])
+    }
+
+    pub fn count(self) -> Self {
+        self.step("count", vec![])
+    }
+
+    pub fn dedup(self) -> Self {
+        self.step("dedup", vec![])
+    }
+
+    pub fn limit(self, limit: i64) -> Self {
+        self.step("limit", vec![limit.into()])
+    }
+
+    pub fn range(self, low: i64, high: i64) -> Self {
+        self.step("range", vec![low.into(), high.into()])
+    }
+
+    pub fn order(self) -> Self {
+        self.step("order", vec![])
+    }
+
+    pub fn by(self, key: impl Into<Value>) -> Self {
+        self.step("by", vec![key.into()])
+    }
+
+    pub fn as_(self, label: impl Into<Value>) -> Self {
+        self.step("as", vec![label.into()])
+    }
+
+    pub fn select(self, labels: impl IntoIterator<Item = impl Into<Value>>) -> Self {
+        self.step("select", labels.into_iter().map(Into::into).collect())
+    }
+
+    pub fn path(self) -> Self {
+        self.step("path", vec![])
+    }
+
+    pub fn fold(self) -> Self {
+        self.step("fold", vec![])
+    }
+
+    pub fn unfold(self) -> Self {
+        self.step("unfold", vec![])
+    }
+
+    pub fn property(self, key: impl Into<Value>, value: impl Into<Value>) -> Self {
+        self.mutating_step("property", vec![key.into(), value.into()])
+    }
+
+    pub fn add_e(self, label: impl Into<Value>) -> Self {
+        self.mutating_step("addE", vec![label.into()])
+    }
+
+    /// Targets the edge created by [`Traversal::add_e`] to the step labeled `label` with [`Traversal::as_`].
+    pub fn to(self, label: impl Into<Value>) -> Self {
+        self.step("to", vec![label.into()])
+    }
+
+    /// Sources the edge created by [`Traversal::add_e`] from the step labeled `label` with [`Traversal::as_`].
+    pub fn from(self, label: impl Into<Value>) -> Self {
+        self.step("from", vec![label.into()])
+    }
+
+    pub fn drop(self) -> Self {
+        self.mutating_step("drop", vec![])
+    }
+
+    /// The rendered Gremlin text.
+    pub fn gremlin(&self) -> &str {
+        &self.text
+    }
+
+    /// The parameters referenced by [`Traversal::gremlin`].
+    pub fn bindings(&self) -> &[(String, Value)] {
+        &self.bindings
+    }
+
+    /// Returns `true` if the traversal contains steps that write to the graph.
+    pub fn is_mutating(&self) -> bool {
+        self.mutating
+    }
+
+    pub(crate) fn statement<'a, 'b, Q: Queryable + Send + Sync>(
+        &'b self,
+        queryable: &'a Q,
+    ) -> Statement<'a, 'b, Q> {
+        let kind = if self.mutating {
+            StatementKind::Command
+        } else {
+            StatementKind::Query
+        };
+        self.bindings.iter().fold(
+            Statement::new(queryable, &self.text, kind).language(Language::Gremlin),
+            |stmt, (name, value)| stmt.param(name, value.clone()),
+        )
+    }
+}
+
+/// A Gremlin predicate used by [`Traversal::has_pred`].
+#[derive(Debug, Clone)]
+pub struct P {
+    name: &'static str,
+    args: Vec<Value>,
+}
+
+impl P {
+    fn new(name: &'static str, args: Vec<Value>) -> Self {
+        P { name, args }
+    }
+
+    pub fn eq(value: impl Into<Value>) -> Self {
+        P::new("eq", vec![value.into()])
+    }
+
+    pub fn neq(value: impl Into<Value>) -> Self {
+        P::new("neq", vec![value.into()])
+    }
+
+    pub fn gt(value: impl Into<Value>) -> Self {
+        P::new("gt", vec![value.into()])
+    }
+
+    pub fn gte(value: impl Into<Value>) -> Self {
+        P::new("gte", vec![value.into()])
+    }
+
+    pub fn lt(value: impl Into<Value>) -> Self {
+        P::new("lt", vec![value.into()])
+    }
+
+    pub fn lte(value: impl Into<Value>) -> Self {
+        P::new("lte", vec![value.into()])
+    }
+
+    pub fn between(low: impl Into<Value>, high: impl Into<Value>) -> Self {
+        P::new("between", vec![low.into(), high.into()])
+    }
+
+    pub fn within(values: impl IntoIterator<Item = impl Into<Value>>) -> Self {
+        P::new("within", values.into_iter().map(Into::into).collect())
+    }
+
+    pub fn without(values: impl IntoIterator<Item = impl Into<Value>>) -> Self {
+        P::new("without", values.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{g, P};
+
+    #[test]
+    fn should_render_traversal_with_bindings() {
+        let traversal = g().V().has_label("Movie").out("ACTED_IN").values("name");
+
+        assert_eq!("g.V().hasLabel(p0).out(p1).values(p2)", traversal.gremlin());
+        assert_eq!(
+            vec![
+                ("p0".to_string(), json!("Movie")),
+                ("p1".to_string(), json!("ACTED_IN")),
+                ("p2".to_string(), json!("name")),
+            ],
+            traversal.bindings()
+        );
+        assert!(!traversal.is_mutating());
+    }
+
+    #[test]
+    fn should_render_predicates() {
+        let traversal = g()
+            .V()
+            .has_pred("released", P::between(1999, 2003))
+            .has_pred("title", P::within(["The Matrix", "Cloud Atlas"]))
+            .count();
+
+        assert_eq!(
+            "g.V().has(p0,between(p1,p2)).has(p3,within(p4,p5)).count()",
+            traversal.gremlin()
+        );
+        assert_eq!(6, traversal.bindings().len());
+    }
+
+    #[test]
+    fn should_mark_write_traversals_as_mutating() {
+        let traversal = g().add_v("Person").property("name", "John");
+
+        assert_eq!("g.addV(p0).property(p1,p2)", traversal.gremlin());
+        assert!(traversal.is_mutating());
+    }
+}
@@ -48,20 +48,47 @@
 //!
 //!
 
+pub mod admin;
+mod auth;
 mod client;
+pub mod codegen;
 mod command;
+pub mod convert;
 mod db;
 mod document;
 mod error;
+mod gremlin;
+pub mod migrations;
 mod options;
 mod protocol;
+pub mod schema;
+mod sql;
 mod transaction;
 mod transport;
 mod types;
 
-pub use client::ArcadeDB;
+pub use auth::{AuthProvider, EnvAuth, FileAuth};
+pub use client::{ArcadeDB, ArcadeDBBuilder};
 pub use command::Language;
 pub use db::Database;
-pub use error::{ArcadeDBError, ErrorResponse};
-pub use options::Auth;
-pub use types::rid::RecordID;
+pub use error::{ArcadeDBError, ErrorResponse, InvalidIdentifier, InvalidRecordID};
+pub use gremlin::{g, GraphTraversalSource, Traversal, P};
+pub use options::{ArcadeDBOptions, Auth, Credentials, Secret};
+pub use protocol::{
+    DatabasesResponse, GenericResponse, HaInfo, ServerEvent, ServerEvents, ServerInfo, ServerMode,
+    UserDefinition,
+};
+pub use sql::{ident, literal};
+pub use types::{
+    link::Link,
+    rid::RecordID,
+    typed::{deserialize_typed, TypedRecord},
+    value::ArcadeValue,
+    version::{Capabilities, ServerVersion},
+};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+    pub use serde_json;
+}
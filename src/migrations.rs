@@ -0,0 +1,651 @@
+//! Versioned schema and data migrations.
+//!
+//! Migrations are applied in version order and recorded in the `_migrations` document type
+//! together with a checksum of their script, so edits to already applied migrations are
+//! detected.
+//!
+//! Migrations running in a transaction are recorded in the same transaction. Migrations
+//! running outside one, i.e. schema changes, are recorded as incomplete before they run and
+//! marked complete afterwards: if marking fails, [`Migrator::up`] reports the migration as
+//! [`MigrationState::Incomplete`] instead of running it again.
+//!
+//! ```rust,no_run
+//! use arcadedb_rs::{
+//!     migrations::{Migration, Migrator},
+//!     ArcadeDB, Auth,
+//! };
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let arcadedb = ArcadeDB::builder()
+//!     .auth(Auth::basic("root", "playwithdata"))
+//!     .build("http://localhost:2480")
+//!     .await?;
+//!
+//! let migrator = Migrator::new(vec![
+//!     Migration::sql_script(
+//!         1,
+//!         "create_person",
+//!         "create vertex type Person; create property Person.name STRING;",
+//!     ),
+//!     Migration::sql(2, "seed_person", "insert into Person set name = 'John'"),
+//! ])?;
+//!
+//! let applied = migrator.up(&arcadedb.db("movies")).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Scripts can also be embedded from files with the [`migration!`](crate::migration) macro.
+
+use std::{collections::HashMap, fmt, future::Future, pin::Pin};
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    command::{Statement, StatementKind},
+    db::Queryable,
+    gremlin::Traversal,
+    schema::{CreateType, TypeKind},
+    sql::{ident, literal},
+    transaction::Transaction,
+    ArcadeDBError, Database, ErrorResponse, Language,
+};
+
+const MIGRATIONS_TYPE: &str = "_migrations";
+
+type MigrationFuture =
+    Pin<Box<dyn Future<Output = Result<(), ArcadeDBError<ErrorResponse>>> + Send>>;
+
+type MigrationFn<T> = Box<dyn Fn(T) -> MigrationFuture + Send + Sync>;
+
+/// Embeds a migration script at compile time.
+///
+/// The file name must follow the `<version>_<name>.<sql|sqlscript>` pattern, and the path
+/// is resolved like [`include_str!`]. Evaluates to the `Result` of [`Migration::from_file`].
+#[macro_export]
+macro_rules! migration {
+    ($path:literal) => {
+        $crate::migrations::Migration::from_file($path, include_str!($path))
+    };
+}
+
+pub struct Migration {
+    version: u64,
+    name: String,
+    kind: MigrationKind,
+    transactional: Option<bool>,
+}
+
+enum MigrationKind {
+    Script { language: Language, script: String },
+    Code(MigrationFn<MigrationTransaction>),
+    CodeWithoutTransaction(MigrationFn<Database>),
+}
+
+impl Migration {
+    /// A migration made of a single SQL statement.
+    pub fn sql(version: u64, name: impl Into<String>, script: impl Into<String>) -> Self {
+        Migration::script(version, name.into(), Language::SQL, script.into())
+    }
+
+    /// A migration made of a `sqlscript`, i.e. several statements separated by `;`.
+    pub fn sql_script(version: u64, name: impl Into<String>, script: impl Into<String>) -> Self {
+        Migration::script(version, name.into(), Language::SqlScript, script.into())
+    }
+
+    /// A migration implemented in Rust, run inside a transaction that is committed
+    /// together with the `_migrations` record. Code migrations have no checksum.
+    pub fn code<F, Fut>(version: u64, name: impl Into<String>, migration: F) -> Self
+    where
+        F: Fn(MigrationTransaction) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), ArcadeDBError<ErrorResponse>>> + Send + 'static,
+    {
+        Migration {
+            version,
+            name: name.into(),
+            kind: MigrationKind::Code(Box::new(move |tx| Box::pin(migration(tx)))),
+            transactional: None,
+        }
+    }
+
+    /// A migration implemented in Rust that runs outside a transaction, for schema changes
+    /// which ArcadeDB does not allow in one.
+    pub fn code_without_transaction<F, Fut>(
+        version: u64,
+        name: impl Into<String>,
+        migration: F,
+    ) -> Self
+    where
+        F: Fn(Database) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), ArcadeDBError<ErrorResponse>>> + Send + 'static,
+    {
+        Migration {
+            version,
+            name: name.into(),
+            kind: MigrationKind::CodeWithoutTransaction(Box::new(move |db| {
+                Box::pin(migration(db))
+            })),
+            transactional: None,
+        }
+    }
+
+    /// Builds a migration from a `<version>_<name>.<sql|sqlscript>` file, see [`migration!`].
+    ///
+    /// Fails if the file name does not start with a numeric version followed by `_`.
+    pub fn from_file(path: &str, script: &str) -> Result<Self, ArcadeDBError<ErrorResponse>> {
+        let file = path.rsplit(['/', '\\']).next().unwrap_or(path);
+        let (stem, extension) = file.rsplit_once('.').unwrap_or((file, "sql"));
+        let (version, name) = stem
+            .split_once('_')
+            .filter(|(_, name)| !name.is_empty())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Migration file {} must be named <version>_<name>.<sql|sqlscript>",
+                    path
+                )
+            })?;
+        let version = version
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Migration file {} must start with a version", path))?;
+        Ok(match extension {
+            "sqlscript" => Migration::sql_script(version, name, script),
+            _ => Migration::sql(version, name, script),
+        })
+    }
+
+    fn script(version: u64, name: String, language: Language, script: String) -> Self {
+        Migration {
+            version,
+            name,
+            kind: MigrationKind::Script { language, script },
+            transactional: None,
+        }
+    }
+
+    /// Forces running a script inside or outside a transaction. By default scripts run in a
+    /// transaction unless they change the schema, which ArcadeDB does not allow. Code
+    /// migrations choose with [`code`](Migration::code) or
+    /// [`code_without_transaction`](Migration::code_without_transaction) instead.
+    pub fn transactional(mut self, transactional: bool) -> Self {
+        self.transactional = Some(transactional);
+        self
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn checksum(&self) -> Option<String> {
+        match &self.kind {
+            MigrationKind::Script { script, .. } => Some(checksum(script)),
+            MigrationKind::Code(_) | MigrationKind::CodeWithoutTransaction(_) => None,
+        }
+    }
+
+    fn is_transactional(&self) -> bool {
+        match &self.kind {
+            MigrationKind::Script { script, .. } => self
+                .transactional
+                .unwrap_or_else(|| !changes_schema(script)),
+            MigrationKind::Code(_) => true,
+            MigrationKind::CodeWithoutTransaction(_) => false,
+        }
+    }
+
+    async fn run(&self, db: &Database) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        match &self.kind {
+            MigrationKind::Code(migration) => {
+                let tx = db.tx().await?;
+                let result = async {
+                    migration(MigrationTransaction(tx.share())).await?;
+                    record(&tx, self, true).await
+                }
+                .await;
+                finish(tx, result).await
+            }
+            MigrationKind::Script { language, script } if self.is_transactional() => {
+                let tx = db.tx().await?;
+                let result = async {
+                    tx.command(script)
+                        .language(*language)
+                        .send::<Value>()
+                        .await?;
+                    record(&tx, self, true).await
+                }
+                .await;
+                finish(tx, result).await
+            }
+            MigrationKind::CodeWithoutTransaction(migration) => {
+                record(db, self, false).await?;
+                let result = migration(db.clone()).await;
+                complete(db, self, result).await
+            }
+            MigrationKind::Script { language, script } => {
+                record(db, self, false).await?;
+                let result = db
+                    .command(script)
+                    .language(*language)
+                    .send::<Value>()
+                    .await
+                    .map(|_| ());
+                complete(db, self, result).await
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Migration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Migration")
+            .field("version", &self.version)
+            .field("name", &self.name)
+            .field("checksum", &self.checksum())
+            .finish()
+    }
+}
+
+/// The transaction of a [`Migration::code`] migration, which the [`Migrator`] commits or
+/// rolls back together with the `_migrations` record.
+pub struct MigrationTransaction(Transaction);
+
+impl MigrationTransaction {
+    pub fn query<'a, 'b>(&'a self, stmt: &'b str) -> Statement<'a, 'b, Transaction> {
+        self.0.query(stmt)
+    }
+    pub fn command<'a, 'b>(&'a self, stmt: &'b str) -> Statement<'a, 'b, Transaction> {
+        self.0.command(stmt)
+    }
+    pub fn gremlin<'a, 'b>(&'a self, traversal: &'b Traversal) -> Statement<'a, 'b, Transaction> {
+        self.0.gremlin(traversal)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationState {
+    Pending,
+    Applied,
+    /// The migration was applied with a different checksum than the current script.
+    Drifted,
+    /// The migration ran outside a transaction but was not marked complete, so it may be
+    /// partially applied. Its `_migrations` record must be fixed by hand.
+    Incomplete,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationStatus {
+    pub version: u64,
+    pub name: String,
+    pub state: MigrationState,
+    pub checksum: Option<String>,
+    pub applied_at: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AppliedMigration {
+    version: u64,
+    checksum: Option<String>,
+    applied_at: Option<Value>,
+    /// Missing in records written before migrations were marked complete.
+    complete: Option<bool>,
+}
+
+/// Applies a set of [`Migration`]s in version order.
+#[derive(Debug)]
+pub struct Migrator {
+    migrations: Vec<Migration>,
+}
+
+impl Migrator {
+    pub fn new(mut migrations: Vec<Migration>) -> Result<Self, ArcadeDBError<ErrorResponse>> {
+        migrations.sort_by_key(|migration| migration.version);
+        if let Some(pair) = migrations
+            .windows(2)
+            .find(|pair| pair[0].version == pair[1].version)
+        {
+            return Err(anyhow::anyhow!(
+                "Duplicate migration version {} ({} and {})",
+                pair[0].version,
+                pair[0].name,
+                pair[1].name
+            )
+            .into());
+        }
+        Ok(Migrator { migrations })
+    }
+
+    pub fn migrations(&self) -> &[Migration] {
+        &self.migrations
+    }
+
+    /// Returns the state of every known migration, without writing to the database.
+    pub async fn status(
+        &self,
+        db: &Database,
+    ) -> Result<Vec<MigrationStatus>, ArcadeDBError<ErrorResponse>> {
+        let applied = applied(db).await?;
+        Ok(self
+            .migrations
+            .iter()
+            .map(|migration| {
+                let record = applied.get(&migration.version);
+                let checksum = migration.checksum();
+                let state = match record {
+                    None => MigrationState::Pending,
+                    Some(record) if record.complete == Some(false) => MigrationState::Incomplete,
+                    Some(record) => match (&record.checksum, &checksum) {
+                        (Some(applied), Some(current)) if applied != current => {
+                            MigrationState::Drifted
+                        }
+                        _ => MigrationState::Applied,
+                    },
+                };
+                MigrationStatus {
+                    version: migration.version,
+                    name: migration.name.clone(),
+                    state,
+                    checksum,
+                    applied_at: record.and_then(|record| record.applied_at.clone()),
+                }
+            })
+            .collect())
+    }
+
+    /// Applies all pending migrations and returns their versions.
+    ///
+    /// Fails without applying anything if an applied migration has drifted or is
+    /// incomplete.
+    pub async fn up(&self, db: &Database) -> Result<Vec<u64>, ArcadeDBError<ErrorResponse>> {
+        let status = self.status(db).await?;
+
+        for (state, message) in [
+            (
+                MigrationState::Drifted,
+                "Applied migrations have been modified",
+            ),
+            (
+                MigrationState::Incomplete,
+                "Migrations may be partially applied",
+            ),
+        ] {
+            let names = status
+                .iter()
+                .filter(|status| status.state == state)
+                .map(|status| format!("{}_{}", status.version, status.name))
+                .collect::<Vec<_>>();
+            if !names.is_empty() {
+                return Err(anyhow::anyhow!("{}: {}", message, names.join(", ")).into());
+            }
+        }
+
+        if status
+            .iter()
+            .any(|status| status.state == MigrationState::Pending)
+        {
+            CreateType::new(TypeKind::Document, MIGRATIONS_TYPE)
+                .if_not_exists()
+                .send(db)
+                .await?;
+        }
+
+        let mut applied = vec![];
+        for (migration, status) in self.migrations.iter().zip(status) {
+            if status.state == MigrationState::Pending {
+                migration
+                    .run(db)
+                    .await
+                    .map_err(|err| failed(migration, err))?;
+                applied.push(migration.version);
+            }
+        }
+        Ok(applied)
+    }
+}
+
+/// Prefixes `err` with the failed migration, keeping the server error typed.
+fn failed(
+    migration: &Migration,
+    err: ArcadeDBError<ErrorResponse>,
+) -> ArcadeDBError<ErrorResponse> {
+    let context = format!("Migration {}_{} failed", migration.version, migration.name);
+    match err {
+        ArcadeDBError::Error(mut response) => {
+            response.error = format!("{}: {}", context, response.error);
+            ArcadeDBError::Error(response)
+        }
+        ArcadeDBError::Generic(err) => anyhow::anyhow!("{}: {:#}", context, err).into(),
+    }
+}
+
+async fn finish(
+    tx: Transaction,
+    result: Result<(), ArcadeDBError<ErrorResponse>>,
+) -> Result<(), ArcadeDBError<ErrorResponse>> {
+    match result {
+        Ok(()) => tx.commit().await,
+        Err(err) => {
+            let _ = tx.rollback().await;
+            Err(err)
+        }
+    }
+}
+
+async fn applied(
+    db: &Database,
+) -> Result<HashMap<u64, AppliedMigration>, ArcadeDBError<ErrorResponse>> {
+    let sql = format!(
+        "select from schema:types where name = {}",
+        literal(MIGRATIONS_TYPE)
+    );
+    if db.query(&sql).send::<Value>().await?.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let sql = format!("select from {}", ident(MIGRATIONS_TYPE)?);
+    Ok(db
+        .query(&sql)
+        .send::<AppliedMigration>()
+        .await?
+        .into_iter()
+        .map(|record| (record.version, record))
+        .collect())
+}
+
+async fn record<Q: Queryable + Send + Sync>(
+    queryable: &Q,
+    migration: &Migration,
+    complete: bool,
+) -> Result<(), ArcadeDBError<ErrorResponse>> {
+    let sql = format!(
+        "insert into {} set version = :version, name = :name, checksum = :checksum, complete = :complete, appliedAt = sysdate()",
+        ident(MIGRATIONS_TYPE)?
+    );
+    Statement::new(queryable, &sql, StatementKind::Command)
+        .param("version", migration.version)
+        .param("name", migration.name.as_str())
+        .param("checksum", migration.checksum())
+        .param("complete", complete)
+        .send::<Value>()
+        .await
+        .map(|_| ())
+}
+
+/// Marks a migration run outside a transaction as complete, or removes its record when it
+/// failed so that it can be retried. A record left incomplete stops [`Migrator::up`].
+async fn complete(
+    db: &Database,
+    migration: &Migration,
+    result: Result<(), ArcadeDBError<ErrorResponse>>,
+) -> Result<(), ArcadeDBError<ErrorResponse>> {
+    let migrations = ident(MIGRATIONS_TYPE)?;
+    let sql = match &result {
+        Ok(()) => format!(
+            "update {} set complete = true, appliedAt = sysdate() where version = :version",
+            migrations
+        ),
+        Err(_) => format!("delete from {} where version = :version", migrations),
+    };
+    let update = db
+        .command(&sql)
+        .param("version", migration.version)
+        .send::<Value>()
+        .await
+        .map(|_| ());
+    result.and(update)
+}
+
+/// FNV-1a, stable across Rust versions and platforms.
+fn checksum(script: &str) -> String {
+    let hash = script.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+fn changes_schema(script: &str) -> bool {
+    statements(script).iter().any(|statement| {
+        let words = statement
+            .split_whitespace()
+            .take(3)
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>();
+        matches!(
+            words
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .as_slice(),
+            [
+                "create" | "alter" | "drop",
+                "vertex" | "edge" | "document",
+                "type",
+                ..
+            ] | [
+                "create" | "alter" | "drop" | "truncate",
+                "type" | "property" | "index" | "bucket",
+                ..
+            ] | ["rebuild", "index", ..]
+        )
+    })
+}
+
+/// Splits a script on `;` into its statements, skipping quoted text and dropping `--` and
+/// `/* */` comments.
+fn statements(script: &str) -> Vec<String> {
+    let mut statements = vec![];
+    let mut current = String::new();
+    let mut quote = None;
+    let mut chars = script.chars().peekable();
+    while let Some(c) = chars.next() {
+        match quote {
+            Some(q) => {
+                current.push(c);
+                if c == '\\' {
+                    current.extend(chars.next());
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None => match c {
+                '\'' | '"' | '`' => {
+                    quote = Some(c);
+                    current.push(c);
+                }
+                '-' if chars.peek() == Some(&'-') => {
+                    chars.by_ref().find(|&c| c == '\n');
+                    current.push('\n');
+                }
+                '/' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    let mut previous = ' ';
+                    chars.by_ref().find(|&c| {
+                        let end = previous == '*' && c == '/';
+                        previous = c;
+                        end
+                    });
+                    current.push(' ');
+                }
+                ';' => statements.push(std::mem::take(&mut current)),
+                c => current.push(c),
+            },
+        }
+    }
+    statements.push(current);
+    statements
+        .into_iter()
+        .map(|statement| statement.trim().to_string())
+        .filter(|statement| !statement.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{changes_schema, checksum, statements, Migration, Migrator};
+
+    #[test]
+    fn should_parse_migration_files() {
+        let migration = Migration::from_file(
+            "migrations/001_create_person.sqlscript",
+            "create vertex type Person;",
+        )
+        .unwrap();
+
+        assert_eq!(1, migration.version());
+        assert_eq!("create_person", migration.name());
+        assert!(!migration.is_transactional());
+    }
+
+    #[test]
+    fn should_reject_migration_files_without_version() {
+        for path in ["create_person.sql", "migrations/001.sql", "v1_person.sql"] {
+            assert!(Migration::from_file(path, "select 1").is_err(), "{}", path);
+        }
+    }
+
+    #[test]
+    fn should_detect_schema_changes() {
+        assert!(changes_schema(
+            "insert into Person set name = 'a';\nCREATE VERTEX TYPE Actor"
+        ));
+        assert!(changes_schema("create property Person.name STRING"));
+        assert!(!changes_schema("create vertex Person set name = 'a'"));
+        assert!(!changes_schema("update Person set name = 'b'"));
+        assert!(changes_schema(
+            "-- adds actors\n\n  /* see #12 */ create vertex type Actor"
+        ));
+        assert!(!changes_schema(
+            "insert into Log set text = '; create type X'"
+        ));
+    }
+
+    #[test]
+    fn should_split_statements() {
+        assert_eq!(
+            vec!["insert into A set a = 'x;--y'", "update A set b = 1"],
+            statements(
+                "-- first\ninsert into A set a = 'x;--y';\n/* a; b */ update A set b = 1;\n"
+            )
+        );
+    }
+
+    #[test]
+    fn should_compute_stable_checksums() {
+        assert_eq!("cbf29ce484222325", checksum(""));
+        assert_eq!("af63dc4c8601ec8c", checksum("a"));
+    }
+
+    #[test]
+    fn should_reject_duplicate_versions() {
+        let error = Migrator::new(vec![
+            Migration::sql(1, "a", "select 1"),
+            Migration::sql(1, "b", "select 2"),
+        ])
+        .unwrap_err();
+
+        assert_eq!("Duplicate migration version 1 (a and b)", error.to_string());
+    }
+}
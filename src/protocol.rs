@@ -1,10 +1,15 @@
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde::{de::DeserializeOwned, ser::Error, Deserialize, Serialize};
+use serde_json::Value;
 use std::{collections::HashMap, fmt::Display, marker::PhantomData};
 
 use crate::{
     command::{Statement, StatementKind},
     db::Queryable,
-    error::ErrorResponse,
+    error::{ErrorResponse, InvalidIdentifier},
+    options::Secret,
+    schema::Setting,
+    sql::token,
+    types::version::ServerVersion,
 };
 
 const SESSION_HEADER: &str = "arcadedb-session-id";
@@ -43,7 +48,7 @@ pub struct Command<'a> {
 }
 
 impl<'a, T> ServerCommandRequest<'a, T> {
-    pub fn new(command: ServerCommand<'a>) -> ServerCommandRequest<'a, GenericResponse> {
+    pub fn new(command: ServerCommand<'a>) -> ServerCommandRequest<'a, T> {
         ServerCommandRequest {
             phantom: PhantomData,
             command: Command { command },
@@ -54,6 +59,15 @@ impl<'a, T> ServerCommandRequest<'a, T> {
 pub enum ServerCommand<'a> {
     CreateDatabase(&'a str),
     DropDatabase(&'a str),
+    OpenDatabase(&'a str),
+    CloseDatabase(&'a str),
+    AlignDatabase(&'a str),
+    CreateUser(&'a UserDefinition),
+    DropUser(&'a str),
+    SetServerSetting(&'a str, &'a str),
+    SetDatabaseSetting(&'a str, &'a str, &'a str),
+    GetServerEvents(Option<&'a str>),
+    Shutdown,
 }
 
 impl<'a> ServerCommand<'a> {
@@ -63,6 +77,47 @@ impl<'a> ServerCommand<'a> {
     pub fn drop_db(db: &'a str) -> Self {
         Self::DropDatabase(db)
     }
+
+    fn to_command(&self) -> Result<String, anyhow::Error> {
+        Ok(match self {
+            ServerCommand::CreateDatabase(db) => format!("CREATE DATABASE {}", token(db)?),
+            ServerCommand::DropDatabase(db) => format!("DROP DATABASE {}", token(db)?),
+            ServerCommand::OpenDatabase(db) => format!("OPEN DATABASE {}", token(db)?),
+            ServerCommand::CloseDatabase(db) => format!("CLOSE DATABASE {}", token(db)?),
+            ServerCommand::AlignDatabase(db) => format!("ALIGN DATABASE {}", token(db)?),
+            ServerCommand::CreateUser(user) => {
+                format!("CREATE USER {}", serde_json::to_string(user)?)
+            }
+            ServerCommand::DropUser(user) => format!("DROP USER {}", token(user)?),
+            ServerCommand::SetServerSetting(key, value) => format!(
+                "SET SERVER SETTING {} {}",
+                token(key)?,
+                setting_value(value)?
+            ),
+            ServerCommand::SetDatabaseSetting(db, key, value) => format!(
+                "SET DATABASE SETTING {} {} {}",
+                token(db)?,
+                token(key)?,
+                setting_value(value)?
+            ),
+            ServerCommand::GetServerEvents(None) => String::from("GET SERVER EVENTS"),
+            ServerCommand::GetServerEvents(Some(file)) => {
+                format!("GET SERVER EVENTS {}", token(file)?)
+            }
+            ServerCommand::Shutdown => String::from("SHUTDOWN"),
+        })
+    }
+}
+
+/// The server splits the setting commands on spaces, so values cannot contain whitespace.
+fn setting_value(value: &str) -> Result<&str, InvalidIdentifier> {
+    if value.is_empty() {
+        return Err(InvalidIdentifier::Empty);
+    }
+    match value.chars().find(|c| c.is_control() || c.is_whitespace()) {
+        Some(c) => Err(InvalidIdentifier::InvalidCharacter(value.to_string(), c)),
+        None => Ok(value),
+    }
 }
 
 impl<'a> Serialize for ServerCommand<'a> {
@@ -70,15 +125,68 @@ impl<'a> Serialize for ServerCommand<'a> {
     where
         S: serde::Serializer,
     {
-        match self {
-            ServerCommand::CreateDatabase(db) => {
-                serializer.serialize_str(&format!("CREATE DATABASE {}", db))
-            }
-            ServerCommand::DropDatabase(db) => {
-                serializer.serialize_str(&format!("DROP DATABASE {}", db))
-            }
+        serializer.serialize_str(&self.to_command().map_err(S::Error::custom)?)
+    }
+}
+
+/// A user to create with [`ArcadeDB::create_user`](crate::ArcadeDB::create_user).
+#[derive(Serialize, Clone, Debug)]
+pub struct UserDefinition {
+    name: String,
+    #[serde(serialize_with = "expose")]
+    password: Secret,
+    databases: HashMap<String, Vec<String>>,
+}
+
+fn expose<S: serde::Serializer>(secret: &Secret, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(secret.expose())
+}
+
+impl UserDefinition {
+    pub fn new(name: impl Into<String>, password: impl Into<Secret>) -> Self {
+        UserDefinition {
+            name: name.into(),
+            password: password.into(),
+            databases: HashMap::new(),
         }
     }
+
+    /// Grants access to `database` with the given groups, e.g. `admin`.
+    pub fn database<I, S>(mut self, database: impl Into<String>, groups: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.databases.insert(
+            database.into(),
+            groups.into_iter().map(Into::into).collect(),
+        );
+        self
+    }
+
+    pub fn password(mut self, password: impl Into<Secret>) -> Self {
+        self.password = password.into();
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn databases(&self) -> &HashMap<String, Vec<String>> {
+        &self.databases
+    }
+
+    /// The values to redact from the errors of `CREATE USER`: the whole payload, so
+    /// that even short passwords are hidden, and the password as is and JSON escaped.
+    pub(crate) fn secrets(&self) -> Vec<String> {
+        let escaped = serde_json::to_string(self.password.expose()).unwrap_or_default();
+        vec![
+            serde_json::to_string(self).unwrap_or_default(),
+            escaped.trim_matches('"').to_string(),
+            self.password.expose().to_string(),
+        ]
+    }
 }
 
 impl<'a, T: DeserializeOwned> Request for ServerCommandRequest<'a, T> {
@@ -106,6 +214,29 @@ pub struct GenericResponse {
     pub result: String,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct ServerEventsResponse {
+    pub result: ServerEvents,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ServerEvents {
+    #[serde(default)]
+    pub events: Vec<ServerEvent>,
+    #[serde(default)]
+    pub files: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ServerEvent {
+    pub time: String,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub component: Option<String>,
+    pub db: Option<String>,
+    pub message: String,
+}
+
 pub struct GetDatabasesRequest;
 
 impl Request for GetDatabasesRequest {
@@ -135,6 +266,104 @@ pub struct DatabasesResponse {
     pub version: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServerMode {
+    Basic,
+    Default,
+    Cluster,
+}
+
+pub struct GetServerRequest {
+    mode: ServerMode,
+}
+
+impl GetServerRequest {
+    pub fn new(mode: ServerMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl Request for GetServerRequest {
+    type Payload = ();
+
+    type Response = ServerInfo;
+
+    type ResponseError = ErrorResponse;
+
+    fn path(&self) -> String {
+        let mode = match self.mode {
+            ServerMode::Basic => "basic",
+            ServerMode::Default => "default",
+            ServerMode::Cluster => "cluster",
+        };
+        format!("/api/v1/server?mode={}", mode)
+    }
+
+    fn method(&self) -> Method {
+        Method::Get
+    }
+
+    fn payload(&self) -> Option<&Self::Payload> {
+        None
+    }
+}
+
+/// The server information returned by `GET /api/v1/server`. Metrics and settings are
+/// only returned by [`ServerMode::Default`], HA details by the default and cluster modes.
+///
+/// The endpoint does not report the server uptime in any mode, so there is no uptime
+/// field; the raw `metrics` are kept for values this struct does not model.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerInfo {
+    pub user: Option<String>,
+    pub version: String,
+    pub server_name: Option<String>,
+    pub metrics: Option<HashMap<String, Value>>,
+    #[serde(default)]
+    pub settings: Vec<Setting>,
+    pub ha: Option<HaInfo>,
+}
+
+impl ServerInfo {
+    pub fn server_version(&self) -> Result<ServerVersion, anyhow::Error> {
+        self.version.parse()
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HaInfo {
+    pub cluster_name: Option<String>,
+    pub leader: Option<String>,
+    pub election_status: Option<String>,
+    pub network: Option<Value>,
+    #[serde(default)]
+    pub databases: Vec<Value>,
+}
+
+pub struct ReadyRequest;
+
+impl Request for ReadyRequest {
+    type Payload = ();
+
+    type Response = ();
+
+    type ResponseError = ErrorResponse;
+
+    fn path(&self) -> String {
+        String::from("/api/v1/ready")
+    }
+
+    fn method(&self) -> Method {
+        Method::Get
+    }
+
+    fn payload(&self) -> Option<&Self::Payload> {
+        None
+    }
+}
+
 pub struct QueryCommand<'a, 'b, T: DeserializeOwned, Q: Queryable> {
     payload: Statement<'a, 'b, Q>,
     session_id: Option<&'a str>,
@@ -300,3 +529,152 @@ impl<'a> Request for RollbackRequest<'a> {
         metadata
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::{protocol::Request, ArcadeDBError, ErrorResponse, ServerVersion};
+
+    use super::{GetServerRequest, ServerCommand, ServerInfo, ServerMode, UserDefinition};
+
+    #[test]
+    fn should_serialize_server_commands() {
+        assert_eq!(
+            "\"CREATE DATABASE 7c9e6679-7425-40de-944b-e07fc1f90ae7\"",
+            serde_json::to_string(&ServerCommand::create_db(
+                "7c9e6679-7425-40de-944b-e07fc1f90ae7"
+            ))
+            .unwrap()
+        );
+        assert_eq!(
+            "\"DROP DATABASE movies\"",
+            serde_json::to_string(&ServerCommand::drop_db("movies")).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_serialize_all_server_commands() {
+        let user = UserDefinition::new("elon", "tesla").database("movies", ["admin"]);
+        let commands = [
+            (
+                ServerCommand::OpenDatabase("movies"),
+                "OPEN DATABASE movies",
+            ),
+            (
+                ServerCommand::CloseDatabase("movies"),
+                "CLOSE DATABASE movies",
+            ),
+            (
+                ServerCommand::AlignDatabase("movies"),
+                "ALIGN DATABASE movies",
+            ),
+            (
+                ServerCommand::CreateUser(&user),
+                r#"CREATE USER {"name":"elon","password":"tesla","databases":{"movies":["admin"]}}"#,
+            ),
+            (ServerCommand::DropUser("elon"), "DROP USER elon"),
+            (
+                ServerCommand::SetServerSetting("arcadedb.server.name", "node-1"),
+                "SET SERVER SETTING arcadedb.server.name node-1",
+            ),
+            (
+                ServerCommand::SetDatabaseSetting("movies", "arcadedb.dateFormat", "yyyy-MM-dd"),
+                "SET DATABASE SETTING movies arcadedb.dateFormat yyyy-MM-dd",
+            ),
+            (ServerCommand::GetServerEvents(None), "GET SERVER EVENTS"),
+            (
+                ServerCommand::GetServerEvents(Some("server-event-log-1.jsonl")),
+                "GET SERVER EVENTS server-event-log-1.jsonl",
+            ),
+            (ServerCommand::Shutdown, "SHUTDOWN"),
+        ];
+
+        for (command, expected) in commands {
+            assert_eq!(
+                Value::from(expected),
+                serde_json::to_value(&command).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_invalid_database_names() {
+        let error = serde_json::to_string(&ServerCommand::create_db("movies\nDROP DATABASE x"))
+            .unwrap_err();
+
+        assert_eq!(
+            "Identifier \"movies\\nDROP DATABASE x\" contains invalid character '\\n'",
+            error.to_string()
+        );
+
+        for name in ["a b", "a;b"] {
+            assert!(serde_json::to_string(&ServerCommand::create_db(name)).is_err());
+            assert!(serde_json::to_string(&ServerCommand::DropUser(name)).is_err());
+        }
+    }
+
+    #[test]
+    fn should_reject_settings_shifting_tokens() {
+        for command in [
+            ServerCommand::SetServerSetting("arcadedb.server name", "node-1"),
+            ServerCommand::SetServerSetting("arcadedb.server.name", "node 1"),
+            ServerCommand::SetServerSetting("arcadedb.server.name", ""),
+            ServerCommand::SetDatabaseSetting("my movies", "arcadedb.dateFormat", "yyyy-MM-dd"),
+            ServerCommand::SetDatabaseSetting("movies", "arcadedb.dateFormat", "yyyy MM dd"),
+        ] {
+            assert!(serde_json::to_string(&command).is_err());
+        }
+    }
+
+    #[test]
+    fn should_deserialize_server_info() {
+        assert_eq!(
+            "/api/v1/server?mode=cluster",
+            GetServerRequest::new(ServerMode::Cluster).path()
+        );
+
+        let info: ServerInfo = serde_json::from_value(json!({
+            "user": "root",
+            "version": "23.9.1 (build 5c5ad5b4/1695650000/main)",
+            "serverName": "ArcadeDB_0",
+            "metrics": { "profiler": { "readCacheUsed": { "space": 0 } } },
+            "settings": [{
+                "key": "arcadedb.server.name",
+                "value": "ArcadeDB_0",
+                "description": "Server name",
+                "overridden": false,
+                "default": "ArcadeDB_0"
+            }],
+            "ha": { "clusterName": "arcadedb", "leader": "ArcadeDB_0", "electionStatus": "DONE" }
+        }))
+        .unwrap();
+
+        assert_eq!(Some("ArcadeDB_0".to_string()), info.server_name);
+        assert_eq!(ServerVersion::new(23, 9, 1), info.server_version().unwrap());
+        assert_eq!(1, info.settings.len());
+        assert!(info.metrics.unwrap().contains_key("profiler"));
+        assert_eq!(Some("ArcadeDB_0".to_string()), info.ha.unwrap().leader);
+    }
+
+    #[test]
+    fn should_redact_user_payloads() {
+        let user = UserDefinition::new("elon", "pw").database("movies", ["admin"]);
+        let command = ServerCommand::CreateUser(&user).to_command().unwrap();
+
+        let err = ArcadeDBError::Error(ErrorResponse {
+            error: format!("Error on command {}", command),
+            detail: None,
+            exception: None,
+            status: None,
+        })
+        .redact(&user.secrets());
+
+        match err {
+            ArcadeDBError::Error(response) => {
+                assert_eq!("Error on command CREATE USER <redacted>", response.error)
+            }
+            ArcadeDBError::Generic(_) => panic!("Expected an error response"),
+        }
+    }
+}
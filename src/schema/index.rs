@@ -0,0 +1,163 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    error::InvalidIdentifier,
+    sql::{ident, sql_value},
+    ArcadeDBError, Database, ErrorResponse,
+};
+
+use super::{execute, idents};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexType {
+    Unique,
+    NotUnique,
+    FullText,
+    Hash,
+    LsmVector,
+}
+
+impl IndexType {
+    fn keyword(&self) -> &'static str {
+        match self {
+            IndexType::Unique => "UNIQUE",
+            IndexType::NotUnique => "NOTUNIQUE",
+            IndexType::FullText => "FULL_TEXT",
+            IndexType::Hash => "HASH",
+            IndexType::LsmVector => "LSM_VECTOR",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum NullStrategy {
+    Skip,
+    Error,
+}
+
+impl NullStrategy {
+    fn keyword(&self) -> &'static str {
+        match self {
+            NullStrategy::Skip => "SKIP",
+            NullStrategy::Error => "ERROR",
+        }
+    }
+}
+
+/// Builder for `CREATE INDEX`.
+pub struct CreateIndex {
+    type_name: String,
+    properties: Vec<String>,
+    index_type: IndexType,
+    if_not_exists: bool,
+    null_strategy: Option<NullStrategy>,
+    metadata: Option<Value>,
+}
+
+impl CreateIndex {
+    pub fn new<I, S>(type_name: impl Into<String>, properties: I, index_type: IndexType) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        CreateIndex {
+            type_name: type_name.into(),
+            properties: properties.into_iter().map(Into::into).collect(),
+            index_type,
+            if_not_exists: false,
+            null_strategy: None,
+            metadata: None,
+        }
+    }
+
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    pub fn null_strategy(mut self, null_strategy: NullStrategy) -> Self {
+        self.null_strategy = Some(null_strategy);
+        self
+    }
+
+    /// Sets the index metadata, e.g. dimensions and similarity function of an [`IndexType::LsmVector`] index.
+    pub fn metadata(mut self, metadata: impl Into<Value>) -> Self {
+        self.metadata = Some(metadata.into());
+        self
+    }
+
+    pub fn to_sql(&self) -> Result<String, InvalidIdentifier> {
+        let mut sql = String::from("CREATE INDEX");
+        if self.if_not_exists {
+            sql.push_str(" IF NOT EXISTS");
+        }
+        sql.push_str(&format!(
+            " ON {} ({}) {}",
+            ident(&self.type_name)?,
+            idents(&self.properties)?,
+            self.index_type.keyword()
+        ));
+        if let Some(null_strategy) = self.null_strategy {
+            sql.push_str(&format!(" NULL_STRATEGY {}", null_strategy.keyword()));
+        }
+        if let Some(metadata) = &self.metadata {
+            sql.push_str(&format!(" METADATA {}", sql_value(metadata)));
+        }
+        Ok(sql)
+    }
+
+    pub async fn send(self, db: &Database) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        execute(db, self.to_sql()).await
+    }
+}
+
+/// Builder for `DROP INDEX`.
+pub struct DropIndex {
+    name: String,
+    if_exists: bool,
+}
+
+impl DropIndex {
+    pub fn new(name: impl Into<String>) -> Self {
+        DropIndex {
+            name: name.into(),
+            if_exists: false,
+        }
+    }
+
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    pub fn to_sql(&self) -> Result<String, InvalidIdentifier> {
+        let mut sql = format!("DROP INDEX {}", ident(&self.name)?);
+        if self.if_exists {
+            sql.push_str(" IF EXISTS");
+        }
+        Ok(sql)
+    }
+
+    pub async fn send(self, db: &Database) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        execute(db, self.to_sql()).await
+    }
+}
+
+/// An index as returned by `select from schema:indexes`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexInfo {
+    pub name: String,
+    pub type_name: Option<String>,
+    #[serde(rename = "type")]
+    pub index_type: Option<String>,
+    #[serde(default)]
+    pub unique: bool,
+    #[serde(default)]
+    pub automatic: bool,
+    #[serde(default)]
+    pub properties: Vec<String>,
+    pub null_strategy: Option<NullStrategy>,
+}
@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{IndexInfo, TypeKind};
+
+/// A snapshot of the database schema, as returned by [`Database::schema`](crate::Database::schema).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schema {
+    pub database: DatabaseInfo,
+    pub types: Vec<TypeInfo>,
+    pub indexes: Vec<IndexInfo>,
+    pub buckets: Vec<BucketInfo>,
+}
+
+impl Schema {
+    pub fn get_type(&self, name: &str) -> Option<&TypeInfo> {
+        self.types.iter().find(|ty| ty.name == name)
+    }
+
+    /// Returns `true` if `name` is `supertype` or inherits from it, directly or transitively.
+    pub fn is_subtype_of(&self, name: &str, supertype: &str) -> bool {
+        if name == supertype {
+            return true;
+        }
+        self.get_type(name)
+            .map(|ty| {
+                ty.super_types
+                    .iter()
+                    .any(|parent| self.is_subtype_of(parent, supertype))
+            })
+            .unwrap_or(false)
+    }
+
+    /// The types inheriting from `name`, directly or transitively.
+    pub fn subtypes(&self, name: &str) -> Vec<&TypeInfo> {
+        self.types
+            .iter()
+            .filter(|ty| ty.name != name && self.is_subtype_of(&ty.name, name))
+            .collect()
+    }
+
+    /// The properties declared by `name` and all of its supertypes.
+    pub fn properties(&self, name: &str) -> Vec<&PropertyInfo> {
+        let mut properties = vec![];
+        if let Some(ty) = self.get_type(name) {
+            for parent in &ty.super_types {
+                properties.extend(self.properties(parent));
+            }
+            properties.retain(|property: &&PropertyInfo| {
+                ty.properties.iter().all(|own| own.name != property.name)
+            });
+            properties.extend(ty.properties.iter());
+        }
+        properties
+    }
+
+    pub fn indexes_of(&self, type_name: &str) -> Vec<&IndexInfo> {
+        self.indexes
+            .iter()
+            .filter(|index| index.type_name.as_deref() == Some(type_name))
+            .collect()
+    }
+}
+
+/// A type as returned by `select from schema:types`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeInfo {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: TypeKind,
+    #[serde(default, rename = "parentTypes")]
+    pub super_types: Vec<String>,
+    #[serde(default)]
+    pub buckets: Vec<String>,
+    pub bucket_selection_strategy: Option<String>,
+    #[serde(default)]
+    pub properties: Vec<PropertyInfo>,
+    #[serde(default)]
+    pub custom: HashMap<String, Value>,
+}
+
+/// A property of a [`TypeInfo`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyInfo {
+    pub name: String,
+    /// The ArcadeDB type name, e.g. `STRING`, parseable into a [`PropertyType`](super::PropertyType).
+    #[serde(rename = "type")]
+    pub property_type: String,
+    pub of_type: Option<String>,
+    #[serde(default)]
+    pub mandatory: bool,
+    #[serde(default)]
+    pub not_null: bool,
+    #[serde(default)]
+    pub read_only: bool,
+    pub min: Option<Value>,
+    pub max: Option<Value>,
+    pub regexp: Option<String>,
+    pub default: Option<Value>,
+    #[serde(default)]
+    pub custom: HashMap<String, Value>,
+}
+
+/// A bucket as returned by `select from schema:buckets`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BucketInfo {
+    pub name: String,
+    pub file_id: Option<i32>,
+}
+
+/// The database configuration as returned by `select from schema:database`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseInfo {
+    pub name: String,
+    pub charset: Option<String>,
+    pub timezone: Option<String>,
+    pub date_format: Option<String>,
+    pub date_time_format: Option<String>,
+    #[serde(default)]
+    pub settings: Vec<Setting>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Setting {
+    pub key: String,
+    pub value: Option<Value>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub overridden: bool,
+    pub default: Option<Value>,
+}
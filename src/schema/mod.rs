@@ -0,0 +1,240 @@
+use serde_json::Value;
+
+use crate::{error::InvalidIdentifier, sql::ident, ArcadeDBError, Database, ErrorResponse};
+
+mod index;
+mod info;
+mod property;
+mod sync;
+mod types;
+
+pub use index::{CreateIndex, DropIndex, IndexInfo, IndexType, NullStrategy};
+pub use info::{BucketInfo, DatabaseInfo, PropertyInfo, Schema, Setting, TypeInfo};
+pub use property::{AlterProperty, CreateProperty, DropProperty, PropertyConstraint, PropertyType};
+pub(crate) use sync::diff;
+pub use sync::{
+    ChangeKind, IndexDefinition, PropertyDefinition, SchemaChange, SchemaDefinition, SyncMode,
+    TypeDefinition,
+};
+pub use types::{AlterType, CreateType, DropType, TypeAlteration, TypeKind};
+
+async fn execute(
+    db: &Database,
+    sql: Result<String, InvalidIdentifier>,
+) -> Result<(), ArcadeDBError<ErrorResponse>> {
+    let sql = sql?;
+    db.command(&sql).send::<Value>().await.map(|_| ())
+}
+
+fn idents(names: &[String]) -> Result<String, InvalidIdentifier> {
+    names
+        .iter()
+        .map(|name| ident(name))
+        .collect::<Result<Vec<_>, _>>()
+        .map(|names| names.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{
+        AlterProperty, AlterType, CreateIndex, CreateProperty, CreateType, DatabaseInfo, DropIndex,
+        DropProperty, DropType, IndexInfo, IndexType, NullStrategy, PropertyConstraint,
+        PropertyType, Schema, TypeAlteration, TypeInfo, TypeKind,
+    };
+
+    #[test]
+    fn should_render_type_statements() {
+        assert_eq!(
+            "CREATE VERTEX TYPE `Actor` IF NOT EXISTS EXTENDS `Person`, `Named` BUCKET `actor_0` BUCKETS 4",
+            CreateType::new(TypeKind::Vertex, "Actor")
+                .if_not_exists()
+                .extends("Person")
+                .extends("Named")
+                .bucket("actor_0")
+                .total_buckets(4)
+                .to_sql()
+                .unwrap()
+        );
+        assert_eq!(
+            "ALTER TYPE `Actor` SUPERTYPE -`Person`",
+            AlterType::new("Actor", TypeAlteration::RemoveSupertype("Person".into()))
+                .to_sql()
+                .unwrap()
+        );
+        assert_eq!(
+            "DROP TYPE `Actor` IF EXISTS UNSAFE",
+            DropType::new("Actor")
+                .if_exists()
+                .unsafe_drop()
+                .to_sql()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn should_render_property_statements() {
+        assert_eq!(
+            "CREATE PROPERTY `Person`.`name` IF NOT EXISTS STRING (MANDATORY true, NOTNULL true, MAX 20, REGEXP '[A-Z].*', DEFAULT 'John')",
+            CreateProperty::new("Person", "name", PropertyType::String)
+                .if_not_exists()
+                .mandatory(true)
+                .not_null(true)
+                .max(20)
+                .regexp("[A-Z].*")
+                .default_value("John")
+                .to_sql()
+                .unwrap()
+        );
+        assert_eq!(
+            "CREATE PROPERTY `Person`.`created` DATETIME (DEFAULT sysdate())",
+            CreateProperty::new("Person", "created", PropertyType::Datetime)
+                .default_expression("sysdate()")
+                .to_sql()
+                .unwrap()
+        );
+        assert_eq!(
+            "CREATE PROPERTY `Person`.`friends` LIST OF `Person`",
+            CreateProperty::new("Person", "friends", PropertyType::List)
+                .of("Person")
+                .to_sql()
+                .unwrap()
+        );
+        assert_eq!(
+            "ALTER PROPERTY `Person`.`age` MIN 0",
+            AlterProperty::new("Person", "age", PropertyConstraint::Min(json!(0)))
+                .to_sql()
+                .unwrap()
+        );
+        assert_eq!(
+            "DROP PROPERTY `Person`.`age` IF EXISTS FORCE",
+            DropProperty::new("Person", "age")
+                .if_exists()
+                .force()
+                .to_sql()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_type_names() {
+        assert!(CreateType::new(TypeKind::Document, "").to_sql().is_err());
+    }
+
+    #[test]
+    fn should_render_index_statements() {
+        assert_eq!(
+            "CREATE INDEX IF NOT EXISTS ON `Person` (`name`, `surname`) UNIQUE NULL_STRATEGY SKIP",
+            CreateIndex::new("Person", ["name", "surname"], IndexType::Unique)
+                .if_not_exists()
+                .null_strategy(NullStrategy::Skip)
+                .to_sql()
+                .unwrap()
+        );
+        assert_eq!(
+            "CREATE INDEX ON `Doc` (`embedding`) LSM_VECTOR METADATA {'dimensions': 3}",
+            CreateIndex::new("Doc", ["embedding"], IndexType::LsmVector)
+                .metadata(json!({ "dimensions": 3 }))
+                .to_sql()
+                .unwrap()
+        );
+        assert_eq!(
+            "DROP INDEX `Person[name]` IF EXISTS",
+            DropIndex::new("Person[name]").if_exists().to_sql().unwrap()
+        );
+    }
+
+    #[test]
+    fn should_deserialize_index_info() {
+        let json = json!({
+            "name": "Person[name]",
+            "typeName": "Person",
+            "type": "LSM_TREE",
+            "unique": true,
+            "automatic": true,
+            "properties": ["name"],
+            "nullStrategy": "SKIP",
+            "fileId": 12
+        });
+
+        assert_eq!(
+            IndexInfo {
+                name: "Person[name]".to_string(),
+                type_name: Some("Person".to_string()),
+                index_type: Some("LSM_TREE".to_string()),
+                unique: true,
+                automatic: true,
+                properties: vec!["name".to_string()],
+                null_strategy: Some(NullStrategy::Skip),
+            },
+            serde_json::from_value(json).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_resolve_inheritance() {
+        let types: Vec<TypeInfo> = serde_json::from_value(json!([
+            {
+                "name": "Person",
+                "type": "vertex",
+                "parentTypes": [],
+                "properties": [
+                    { "name": "name", "type": "STRING", "mandatory": true },
+                    { "name": "age", "type": "INTEGER" }
+                ]
+            },
+            {
+                "name": "Actor",
+                "type": "vertex",
+                "parentTypes": ["Person"],
+                "properties": [{ "name": "age", "type": "LONG" }]
+            },
+            {
+                "name": "Star",
+                "type": "vertex",
+                "parentTypes": ["Actor"]
+            },
+            { "name": "ACTED_IN", "type": "edge" }
+        ]))
+        .unwrap();
+        let schema = Schema {
+            database: DatabaseInfo {
+                name: "movies".to_string(),
+                charset: None,
+                timezone: None,
+                date_format: None,
+                date_time_format: None,
+                settings: vec![],
+            },
+            types,
+            indexes: vec![],
+            buckets: vec![],
+        };
+
+        assert_eq!(TypeKind::Edge, schema.get_type("ACTED_IN").unwrap().kind);
+        assert!(schema.is_subtype_of("Star", "Person"));
+        assert!(!schema.is_subtype_of("Person", "Star"));
+        assert_eq!(
+            vec!["Actor", "Star"],
+            schema
+                .subtypes("Person")
+                .iter()
+                .map(|ty| ty.name.as_str())
+                .collect::<Vec<_>>()
+        );
+
+        let properties = schema.properties("Star");
+        assert_eq!(
+            vec![("name", "STRING"), ("age", "LONG")],
+            properties
+                .iter()
+                .map(|p| (p.name.as_str(), p.property_type.as_str()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            PropertyType::Long,
+            properties[1].property_type.parse().unwrap()
+        );
+    }
+}
@@ -0,0 +1,317 @@
+use std::str::FromStr;
+
+use serde_json::Value;
+
+use crate::{
+    error::InvalidIdentifier,
+    sql::{ident, literal, sql_value},
+    ArcadeDBError, Database, ErrorResponse,
+};
+
+use super::execute;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyType {
+    Boolean,
+    Byte,
+    Short,
+    Integer,
+    Long,
+    Float,
+    Double,
+    Decimal,
+    String,
+    Binary,
+    Date,
+    Datetime,
+    Embedded,
+    List,
+    Map,
+    Link,
+}
+
+impl PropertyType {
+    pub(crate) fn keyword(&self) -> &'static str {
+        match self {
+            PropertyType::Boolean => "BOOLEAN",
+            PropertyType::Byte => "BYTE",
+            PropertyType::Short => "SHORT",
+            PropertyType::Integer => "INTEGER",
+            PropertyType::Long => "LONG",
+            PropertyType::Float => "FLOAT",
+            PropertyType::Double => "DOUBLE",
+            PropertyType::Decimal => "DECIMAL",
+            PropertyType::String => "STRING",
+            PropertyType::Binary => "BINARY",
+            PropertyType::Date => "DATE",
+            PropertyType::Datetime => "DATETIME",
+            PropertyType::Embedded => "EMBEDDED",
+            PropertyType::List => "LIST",
+            PropertyType::Map => "MAP",
+            PropertyType::Link => "LINK",
+        }
+    }
+}
+
+impl FromStr for PropertyType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PROPERTY_TYPES
+            .iter()
+            .find(|property_type| property_type.keyword().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("Unsupported property type {}", s))
+    }
+}
+
+const PROPERTY_TYPES: [PropertyType; 16] = [
+    PropertyType::Boolean,
+    PropertyType::Byte,
+    PropertyType::Short,
+    PropertyType::Integer,
+    PropertyType::Long,
+    PropertyType::Float,
+    PropertyType::Double,
+    PropertyType::Decimal,
+    PropertyType::String,
+    PropertyType::Binary,
+    PropertyType::Date,
+    PropertyType::Datetime,
+    PropertyType::Embedded,
+    PropertyType::List,
+    PropertyType::Map,
+    PropertyType::Link,
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyConstraint {
+    Mandatory(bool),
+    NotNull(bool),
+    ReadOnly(bool),
+    Min(Value),
+    Max(Value),
+    Regexp(String),
+    Default(Value),
+    /// A default computed by an SQL expression like `sysdate()`, rendered verbatim.
+    DefaultExpression(String),
+}
+
+impl PropertyConstraint {
+    fn to_sql(&self) -> String {
+        match self {
+            PropertyConstraint::Mandatory(value) => format!("MANDATORY {}", value),
+            PropertyConstraint::NotNull(value) => format!("NOTNULL {}", value),
+            PropertyConstraint::ReadOnly(value) => format!("READONLY {}", value),
+            PropertyConstraint::Min(value) => format!("MIN {}", sql_value(value)),
+            PropertyConstraint::Max(value) => format!("MAX {}", sql_value(value)),
+            PropertyConstraint::Regexp(value) => format!("REGEXP {}", literal(value)),
+            PropertyConstraint::Default(value) => format!("DEFAULT {}", sql_value(value)),
+            PropertyConstraint::DefaultExpression(expression) => format!("DEFAULT {}", expression),
+        }
+    }
+}
+
+/// Builder for `CREATE PROPERTY`.
+///
+/// ```rust,no_run
+/// use arcadedb_rs::{
+///     schema::{CreateProperty, PropertyType},
+///     Database,
+/// };
+///
+/// # async fn run(db: Database) -> Result<(), Box<dyn std::error::Error>> {
+/// CreateProperty::new("Person", "created", PropertyType::Datetime)
+///     .default_expression("sysdate()")
+///     .send(&db)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct CreateProperty {
+    type_name: String,
+    name: String,
+    property_type: PropertyType,
+    of_type: Option<String>,
+    if_not_exists: bool,
+    constraints: Vec<PropertyConstraint>,
+}
+
+impl CreateProperty {
+    pub fn new(
+        type_name: impl Into<String>,
+        name: impl Into<String>,
+        property_type: PropertyType,
+    ) -> Self {
+        CreateProperty {
+            type_name: type_name.into(),
+            name: name.into(),
+            property_type,
+            of_type: None,
+            if_not_exists: false,
+            constraints: vec![],
+        }
+    }
+
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Sets the type of the linked or embedded records, or the item type of a `LIST` or `MAP`.
+    pub fn of(mut self, of_type: impl Into<String>) -> Self {
+        self.of_type = Some(of_type.into());
+        self
+    }
+
+    pub fn constraint(mut self, constraint: PropertyConstraint) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    pub fn mandatory(self, mandatory: bool) -> Self {
+        self.constraint(PropertyConstraint::Mandatory(mandatory))
+    }
+
+    pub fn not_null(self, not_null: bool) -> Self {
+        self.constraint(PropertyConstraint::NotNull(not_null))
+    }
+
+    pub fn read_only(self, read_only: bool) -> Self {
+        self.constraint(PropertyConstraint::ReadOnly(read_only))
+    }
+
+    pub fn min(self, min: impl Into<Value>) -> Self {
+        self.constraint(PropertyConstraint::Min(min.into()))
+    }
+
+    pub fn max(self, max: impl Into<Value>) -> Self {
+        self.constraint(PropertyConstraint::Max(max.into()))
+    }
+
+    pub fn regexp(self, regexp: impl Into<String>) -> Self {
+        self.constraint(PropertyConstraint::Regexp(regexp.into()))
+    }
+
+    pub fn default_value(self, value: impl Into<Value>) -> Self {
+        self.constraint(PropertyConstraint::Default(value.into()))
+    }
+
+    /// Sets a default computed by `expression`, e.g. `sysdate()`. The expression is
+    /// not escaped, so it must not come from untrusted input.
+    pub fn default_expression(self, expression: impl Into<String>) -> Self {
+        self.constraint(PropertyConstraint::DefaultExpression(expression.into()))
+    }
+
+    pub fn to_sql(&self) -> Result<String, InvalidIdentifier> {
+        let mut sql = format!(
+            "CREATE PROPERTY {}.{}",
+            ident(&self.type_name)?,
+            ident(&self.name)?
+        );
+        if self.if_not_exists {
+            sql.push_str(" IF NOT EXISTS");
+        }
+        sql.push(' ');
+        sql.push_str(self.property_type.keyword());
+        if let Some(of_type) = &self.of_type {
+            sql.push_str(&format!(" OF {}", ident(of_type)?));
+        }
+        if !self.constraints.is_empty() {
+            let constraints = self
+                .constraints
+                .iter()
+                .map(PropertyConstraint::to_sql)
+                .collect::<Vec<_>>();
+            sql.push_str(&format!(" ({})", constraints.join(", ")));
+        }
+        Ok(sql)
+    }
+
+    pub async fn send(self, db: &Database) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        execute(db, self.to_sql()).await
+    }
+}
+
+/// Builder for `ALTER PROPERTY`.
+pub struct AlterProperty {
+    type_name: String,
+    name: String,
+    constraint: PropertyConstraint,
+}
+
+impl AlterProperty {
+    pub fn new(
+        type_name: impl Into<String>,
+        name: impl Into<String>,
+        constraint: PropertyConstraint,
+    ) -> Self {
+        AlterProperty {
+            type_name: type_name.into(),
+            name: name.into(),
+            constraint,
+        }
+    }
+
+    pub fn to_sql(&self) -> Result<String, InvalidIdentifier> {
+        Ok(format!(
+            "ALTER PROPERTY {}.{} {}",
+            ident(&self.type_name)?,
+            ident(&self.name)?,
+            self.constraint.to_sql()
+        ))
+    }
+
+    pub async fn send(self, db: &Database) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        execute(db, self.to_sql()).await
+    }
+}
+
+/// Builder for `DROP PROPERTY`.
+pub struct DropProperty {
+    type_name: String,
+    name: String,
+    if_exists: bool,
+    force: bool,
+}
+
+impl DropProperty {
+    pub fn new(type_name: impl Into<String>, name: impl Into<String>) -> Self {
+        DropProperty {
+            type_name: type_name.into(),
+            name: name.into(),
+            if_exists: false,
+            force: false,
+        }
+    }
+
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Drops the property even if indexes are defined on it.
+    pub fn force(mut self) -> Self {
+        self.force = true;
+        self
+    }
+
+    pub fn to_sql(&self) -> Result<String, InvalidIdentifier> {
+        let mut sql = format!(
+            "DROP PROPERTY {}.{}",
+            ident(&self.type_name)?,
+            ident(&self.name)?
+        );
+        if self.if_exists {
+            sql.push_str(" IF EXISTS");
+        }
+        if self.force {
+            sql.push_str(" FORCE");
+        }
+        Ok(sql)
+    }
+
+    pub async fn send(self, db: &Database) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        execute(db, self.to_sql()).await
+    }
+}
@@ -0,0 +1,741 @@
+use serde_json::Value;
+
+use crate::{ArcadeDBError, ErrorResponse};
+
+use super::{
+    AlterProperty, AlterType, CreateIndex, CreateProperty, CreateType, DropIndex, DropProperty,
+    DropType, IndexInfo, IndexType, NullStrategy, PropertyConstraint, PropertyInfo, PropertyType,
+    Schema, TypeAlteration, TypeInfo, TypeKind,
+};
+
+/// The desired schema of a database, applied with [`Database::schema_sync`](crate::Database::schema_sync).
+///
+/// ```rust
+/// use arcadedb_rs::schema::{
+///     IndexDefinition, IndexType, PropertyDefinition, PropertyType, SchemaDefinition,
+///     TypeDefinition, TypeKind,
+/// };
+///
+/// let desired = SchemaDefinition::new()
+///     .with_type(
+///         TypeDefinition::new(TypeKind::Vertex, "Person")
+///             .property(PropertyDefinition::new("name", PropertyType::String).mandatory(true)),
+///     )
+///     .with_type(TypeDefinition::new(TypeKind::Vertex, "Actor").extends("Person"))
+///     .with_index(IndexDefinition::new("Person", ["name"], IndexType::Unique));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SchemaDefinition {
+    types: Vec<TypeDefinition>,
+    indexes: Vec<IndexDefinition>,
+    prune: bool,
+}
+
+impl SchemaDefinition {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_type(mut self, definition: TypeDefinition) -> Self {
+        self.types.push(definition);
+        self
+    }
+
+    pub fn with_index(mut self, definition: IndexDefinition) -> Self {
+        self.indexes.push(definition);
+        self
+    }
+
+    /// Drops types, properties and indexes that exist in the database but are not declared.
+    /// Types whose name starts with `_`, like the `_migrations` bookkeeping type, are kept.
+    pub fn prune(mut self, prune: bool) -> Self {
+        self.prune = prune;
+        self
+    }
+
+    pub fn types(&self) -> &[TypeDefinition] {
+        &self.types
+    }
+
+    pub fn indexes(&self) -> &[IndexDefinition] {
+        &self.indexes
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TypeDefinition {
+    kind: TypeKind,
+    name: String,
+    super_types: Vec<String>,
+    properties: Vec<PropertyDefinition>,
+}
+
+impl TypeDefinition {
+    pub fn new(kind: TypeKind, name: impl Into<String>) -> Self {
+        TypeDefinition {
+            kind,
+            name: name.into(),
+            super_types: vec![],
+            properties: vec![],
+        }
+    }
+
+    pub fn extends(mut self, supertype: impl Into<String>) -> Self {
+        self.super_types.push(supertype.into());
+        self
+    }
+
+    pub fn property(mut self, definition: PropertyDefinition) -> Self {
+        self.properties.push(definition);
+        self
+    }
+
+    pub fn kind(&self) -> TypeKind {
+        self.kind
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn super_types(&self) -> &[String] {
+        &self.super_types
+    }
+
+    pub fn properties(&self) -> &[PropertyDefinition] {
+        &self.properties
+    }
+}
+
+/// A declared property. Constraints left unset are not managed by
+/// [`Database::schema_sync`](crate::Database::schema_sync).
+#[derive(Debug, Clone)]
+pub struct PropertyDefinition {
+    name: String,
+    property_type: PropertyType,
+    of_type: Option<String>,
+    mandatory: Option<bool>,
+    not_null: Option<bool>,
+    read_only: Option<bool>,
+    min: Option<Value>,
+    max: Option<Value>,
+    regexp: Option<String>,
+    default: Option<Value>,
+}
+
+impl PropertyDefinition {
+    pub fn new(name: impl Into<String>, property_type: PropertyType) -> Self {
+        PropertyDefinition {
+            name: name.into(),
+            property_type,
+            of_type: None,
+            mandatory: None,
+            not_null: None,
+            read_only: None,
+            min: None,
+            max: None,
+            regexp: None,
+            default: None,
+        }
+    }
+
+    pub fn of(mut self, of_type: impl Into<String>) -> Self {
+        self.of_type = Some(of_type.into());
+        self
+    }
+
+    pub fn mandatory(mut self, mandatory: bool) -> Self {
+        self.mandatory = Some(mandatory);
+        self
+    }
+
+    pub fn not_null(mut self, not_null: bool) -> Self {
+        self.not_null = Some(not_null);
+        self
+    }
+
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = Some(read_only);
+        self
+    }
+
+    pub fn min(mut self, min: impl Into<Value>) -> Self {
+        self.min = Some(min.into());
+        self
+    }
+
+    pub fn max(mut self, max: impl Into<Value>) -> Self {
+        self.max = Some(max.into());
+        self
+    }
+
+    pub fn regexp(mut self, regexp: impl Into<String>) -> Self {
+        self.regexp = Some(regexp.into());
+        self
+    }
+
+    pub fn default_value(mut self, value: impl Into<Value>) -> Self {
+        self.default = Some(value.into());
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn property_type(&self) -> PropertyType {
+        self.property_type
+    }
+
+    /// Whether the property is declared mandatory, `None` when left unmanaged.
+    pub fn is_mandatory(&self) -> Option<bool> {
+        self.mandatory
+    }
+
+    pub fn is_not_null(&self) -> Option<bool> {
+        self.not_null
+    }
+
+    pub fn is_read_only(&self) -> Option<bool> {
+        self.read_only
+    }
+
+    fn constraints(&self) -> Vec<PropertyConstraint> {
+        let mut constraints = vec![];
+        if self.mandatory == Some(true) {
+            constraints.push(PropertyConstraint::Mandatory(true));
+        }
+        if self.not_null == Some(true) {
+            constraints.push(PropertyConstraint::NotNull(true));
+        }
+        if self.read_only == Some(true) {
+            constraints.push(PropertyConstraint::ReadOnly(true));
+        }
+        if let Some(min) = &self.min {
+            constraints.push(PropertyConstraint::Min(min.clone()));
+        }
+        if let Some(max) = &self.max {
+            constraints.push(PropertyConstraint::Max(max.clone()));
+        }
+        if let Some(regexp) = &self.regexp {
+            constraints.push(PropertyConstraint::Regexp(regexp.clone()));
+        }
+        if let Some(default) = &self.default {
+            constraints.push(PropertyConstraint::Default(default.clone()));
+        }
+        constraints
+    }
+
+    fn changes(&self, live: &PropertyInfo) -> Vec<PropertyConstraint> {
+        let mut constraints = vec![];
+        match self.mandatory {
+            Some(mandatory) if mandatory != live.mandatory => {
+                constraints.push(PropertyConstraint::Mandatory(mandatory))
+            }
+            _ => {}
+        }
+        match self.not_null {
+            Some(not_null) if not_null != live.not_null => {
+                constraints.push(PropertyConstraint::NotNull(not_null))
+            }
+            _ => {}
+        }
+        match self.read_only {
+            Some(read_only) if read_only != live.read_only => {
+                constraints.push(PropertyConstraint::ReadOnly(read_only))
+            }
+            _ => {}
+        }
+        match &self.min {
+            Some(min) if !same_value(min, live.min.as_ref()) => {
+                constraints.push(PropertyConstraint::Min(min.clone()))
+            }
+            _ => {}
+        }
+        match &self.max {
+            Some(max) if !same_value(max, live.max.as_ref()) => {
+                constraints.push(PropertyConstraint::Max(max.clone()))
+            }
+            _ => {}
+        }
+        match &self.regexp {
+            Some(regexp) if Some(regexp) != live.regexp.as_ref() => {
+                constraints.push(PropertyConstraint::Regexp(regexp.clone()))
+            }
+            _ => {}
+        }
+        match &self.default {
+            Some(default) if !same_value(default, live.default.as_ref()) => {
+                constraints.push(PropertyConstraint::Default(default.clone()))
+            }
+            _ => {}
+        }
+        constraints
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexDefinition {
+    type_name: String,
+    properties: Vec<String>,
+    index_type: IndexType,
+    null_strategy: Option<NullStrategy>,
+}
+
+impl IndexDefinition {
+    pub fn new<I, S>(type_name: impl Into<String>, properties: I, index_type: IndexType) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        IndexDefinition {
+            type_name: type_name.into(),
+            properties: properties.into_iter().map(Into::into).collect(),
+            index_type,
+            null_strategy: None,
+        }
+    }
+
+    pub fn null_strategy(mut self, null_strategy: NullStrategy) -> Self {
+        self.null_strategy = Some(null_strategy);
+        self
+    }
+
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    pub fn properties(&self) -> &[String] {
+        &self.properties
+    }
+
+    pub fn index_type(&self) -> IndexType {
+        self.index_type
+    }
+
+    fn matches(&self, live: &IndexInfo) -> bool {
+        live.type_name.as_deref() == Some(self.type_name.as_str())
+            && live.properties == self.properties
+    }
+
+    fn same_kind(&self, live: &IndexInfo) -> bool {
+        let kind = live.index_type.as_deref().unwrap_or("LSM_TREE");
+        match self.index_type {
+            IndexType::Unique => kind == "LSM_TREE" && live.unique,
+            IndexType::NotUnique => kind == "LSM_TREE" && !live.unique,
+            IndexType::FullText => kind == "FULL_TEXT",
+            IndexType::Hash => kind.contains("HASH"),
+            IndexType::LsmVector => kind.contains("VECTOR"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncMode {
+    /// Computes the changes without executing them.
+    DryRun,
+    Apply,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    CreateType,
+    AlterType,
+    DropType,
+    CreateProperty,
+    AlterProperty,
+    DropProperty,
+    CreateIndex,
+    DropIndex,
+}
+
+/// A single statement needed to move the live schema towards a [`SchemaDefinition`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaChange {
+    pub kind: ChangeKind,
+    pub sql: String,
+}
+
+pub(crate) fn diff(
+    live: &Schema,
+    desired: &SchemaDefinition,
+) -> Result<Vec<SchemaChange>, ArcadeDBError<ErrorResponse>> {
+    let mut creates = vec![];
+    let mut drops = vec![];
+
+    for definition in sorted(&desired.types, |ty| &ty.name, |ty| &ty.super_types) {
+        match live.get_type(&definition.name) {
+            None => {
+                let mut create = CreateType::new(definition.kind, definition.name.clone());
+                for supertype in &definition.super_types {
+                    create = create.extends(supertype.clone());
+                }
+                creates.push(change(ChangeKind::CreateType, create.to_sql()?));
+                for property in &definition.properties {
+                    creates.push(create_property(definition, property)?);
+                }
+            }
+            Some(ty) => {
+                diff_type(ty, definition, desired.prune, &mut creates, &mut drops)?;
+            }
+        }
+    }
+
+    for definition in &desired.indexes {
+        match live.indexes.iter().find(|index| definition.matches(index)) {
+            Some(index) if definition.same_kind(index) => {}
+            existing => {
+                if let Some(index) = existing {
+                    drops.push(change(
+                        ChangeKind::DropIndex,
+                        DropIndex::new(index.name.clone()).to_sql()?,
+                    ));
+                }
+                let mut create = CreateIndex::new(
+                    definition.type_name.clone(),
+                    definition.properties.clone(),
+                    definition.index_type,
+                );
+                if let Some(null_strategy) = definition.null_strategy {
+                    create = create.null_strategy(null_strategy);
+                }
+                creates.push(change(ChangeKind::CreateIndex, create.to_sql()?));
+            }
+        }
+    }
+
+    if desired.prune {
+        for index in &live.indexes {
+            let type_name = index.type_name.as_deref().unwrap_or_default();
+            if type_name.starts_with('_') {
+                continue;
+            }
+            let declared = desired
+                .indexes
+                .iter()
+                .any(|index_def| index_def.matches(index));
+            // Indexes on pruned properties must go first, or dropping the property fails.
+            let on_pruned_property = desired
+                .types
+                .iter()
+                .find(|def| def.name == type_name)
+                .is_some_and(|def| {
+                    index
+                        .properties
+                        .iter()
+                        .any(|name| def.properties.iter().all(|p| &p.name != name))
+                });
+            if (index.automatic && !declared) || on_pruned_property {
+                drops.push(change(
+                    ChangeKind::DropIndex,
+                    DropIndex::new(index.name.clone()).to_sql()?,
+                ));
+            }
+        }
+        let undeclared = live
+            .types
+            .iter()
+            .filter(|ty| !ty.name.starts_with('_'))
+            .filter(|ty| desired.types.iter().all(|def| def.name != ty.name))
+            .cloned()
+            .collect::<Vec<_>>();
+        for ty in sorted(&undeclared, |ty| &ty.name, |ty| &ty.super_types)
+            .into_iter()
+            .rev()
+        {
+            drops.push(change(
+                ChangeKind::DropType,
+                DropType::new(ty.name.clone()).to_sql()?,
+            ));
+        }
+    }
+
+    drops.sort_by_key(|change| match change.kind {
+        ChangeKind::DropIndex => 0,
+        ChangeKind::DropProperty => 1,
+        _ => 2,
+    });
+    drops.extend(creates);
+    Ok(drops)
+}
+
+fn diff_type(
+    live: &TypeInfo,
+    definition: &TypeDefinition,
+    prune: bool,
+    creates: &mut Vec<SchemaChange>,
+    drops: &mut Vec<SchemaChange>,
+) -> Result<(), ArcadeDBError<ErrorResponse>> {
+    if live.kind != definition.kind {
+        return Err(anyhow::anyhow!(
+            "Type {} is declared as {:?} but exists as {:?}",
+            definition.name,
+            definition.kind,
+            live.kind
+        )
+        .into());
+    }
+
+    for supertype in &definition.super_types {
+        if !live.super_types.contains(supertype) {
+            let alteration = TypeAlteration::AddSupertype(supertype.clone());
+            creates.push(change(
+                ChangeKind::AlterType,
+                AlterType::new(definition.name.clone(), alteration).to_sql()?,
+            ));
+        }
+    }
+    for supertype in &live.super_types {
+        if !definition.super_types.contains(supertype) {
+            let alteration = TypeAlteration::RemoveSupertype(supertype.clone());
+            creates.push(change(
+                ChangeKind::AlterType,
+                AlterType::new(definition.name.clone(), alteration).to_sql()?,
+            ));
+        }
+    }
+
+    for property in &definition.properties {
+        match live.properties.iter().find(|p| p.name == property.name) {
+            None => creates.push(create_property(definition, property)?),
+            Some(existing) => {
+                let live_type = existing.property_type.parse::<PropertyType>().ok();
+                if live_type != Some(property.property_type) {
+                    return Err(anyhow::anyhow!(
+                        "Property {}.{} is declared as {} but exists as {}",
+                        definition.name,
+                        property.name,
+                        property.property_type.keyword(),
+                        existing.property_type
+                    )
+                    .into());
+                }
+                for constraint in property.changes(existing) {
+                    creates.push(change(
+                        ChangeKind::AlterProperty,
+                        AlterProperty::new(
+                            definition.name.clone(),
+                            property.name.clone(),
+                            constraint,
+                        )
+                        .to_sql()?,
+                    ));
+                }
+            }
+        }
+    }
+
+    if prune {
+        for property in &live.properties {
+            if definition
+                .properties
+                .iter()
+                .all(|p| p.name != property.name)
+            {
+                drops.push(change(
+                    ChangeKind::DropProperty,
+                    DropProperty::new(definition.name.clone(), property.name.clone()).to_sql()?,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn create_property(
+    ty: &TypeDefinition,
+    property: &PropertyDefinition,
+) -> Result<SchemaChange, ArcadeDBError<ErrorResponse>> {
+    let mut create = CreateProperty::new(
+        ty.name.clone(),
+        property.name.clone(),
+        property.property_type,
+    );
+    if let Some(of_type) = &property.of_type {
+        create = create.of(of_type.clone());
+    }
+    for constraint in property.constraints() {
+        create = create.constraint(constraint);
+    }
+    Ok(change(ChangeKind::CreateProperty, create.to_sql()?))
+}
+
+fn change(kind: ChangeKind, sql: String) -> SchemaChange {
+    SchemaChange { kind, sql }
+}
+
+/// Orders `items` so that every item comes after the supertypes declared in the same slice.
+fn sorted<T>(items: &[T], name: fn(&T) -> &String, super_types: fn(&T) -> &Vec<String>) -> Vec<&T> {
+    let mut sorted: Vec<&T> = vec![];
+    let mut pending: Vec<&T> = items.iter().collect();
+    while !pending.is_empty() {
+        let (ready, blocked): (Vec<&T>, Vec<&T>) = pending.iter().partition(|item| {
+            super_types(item).iter().all(|parent| {
+                sorted.iter().any(|done| name(done) == parent)
+                    || items.iter().all(|other| name(other) != parent)
+            })
+        });
+        if ready.is_empty() {
+            sorted.extend(blocked);
+            break;
+        }
+        sorted.extend(ready);
+        pending = blocked;
+    }
+    sorted
+}
+
+/// Compares a declared value with the one reported by the server, which returns
+/// numeric constraints as strings.
+fn same_value(desired: &Value, live: Option<&Value>) -> bool {
+    match (desired, live) {
+        (_, None) => desired.is_null(),
+        (Value::String(desired), Some(Value::String(live))) => desired == live,
+        (desired, Some(Value::String(live))) => &desired.to_string() == live,
+        (desired, Some(live)) => desired == live,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::schema::{
+        IndexDefinition, IndexType, PropertyDefinition, PropertyType, Schema, SchemaDefinition,
+        TypeDefinition, TypeKind,
+    };
+
+    use super::{diff, ChangeKind};
+
+    fn live() -> Schema {
+        Schema {
+            database: serde_json::from_value(json!({ "name": "schema" })).unwrap(),
+            types: serde_json::from_value(json!([
+                {
+                    "name": "Person",
+                    "type": "vertex",
+                    "properties": [
+                        { "name": "name", "type": "STRING", "max": "20" },
+                        { "name": "nickname", "type": "STRING", "mandatory": true }
+                    ]
+                },
+                { "name": "Legacy", "type": "document" },
+                { "name": "_migrations", "type": "document" }
+            ]))
+            .unwrap(),
+            indexes: serde_json::from_value(json!([
+                {
+                    "name": "Person[name]",
+                    "typeName": "Person",
+                    "type": "LSM_TREE",
+                    "unique": false,
+                    "automatic": true,
+                    "properties": ["name"]
+                },
+                {
+                    "name": "Person[nickname]",
+                    "typeName": "Person",
+                    "type": "LSM_TREE",
+                    "unique": false,
+                    "automatic": false,
+                    "properties": ["nickname"]
+                },
+                {
+                    "name": "_migrations[version]",
+                    "typeName": "_migrations",
+                    "type": "LSM_TREE",
+                    "unique": true,
+                    "automatic": true,
+                    "properties": ["version"]
+                }
+            ]))
+            .unwrap(),
+            buckets: vec![],
+        }
+    }
+
+    fn desired() -> SchemaDefinition {
+        SchemaDefinition::new()
+            .with_type(TypeDefinition::new(TypeKind::Vertex, "Actor").extends("Person"))
+            .with_type(
+                TypeDefinition::new(TypeKind::Vertex, "Person")
+                    .property(
+                        PropertyDefinition::new("name", PropertyType::String)
+                            .mandatory(true)
+                            .max(20),
+                    )
+                    .property(PropertyDefinition::new("nickname", PropertyType::String)),
+            )
+            .with_index(IndexDefinition::new("Person", ["name"], IndexType::Unique))
+    }
+
+    #[test]
+    fn should_diff_against_live_schema() {
+        let changes = diff(&live(), &desired()).unwrap();
+
+        assert_eq!(
+            vec![
+                (ChangeKind::DropIndex, "DROP INDEX `Person[name]`"),
+                (
+                    ChangeKind::AlterProperty,
+                    "ALTER PROPERTY `Person`.`name` MANDATORY true"
+                ),
+                (
+                    ChangeKind::CreateType,
+                    "CREATE VERTEX TYPE `Actor` EXTENDS `Person`"
+                ),
+                (
+                    ChangeKind::CreateIndex,
+                    "CREATE INDEX ON `Person` (`name`) UNIQUE"
+                ),
+            ],
+            changes
+                .iter()
+                .map(|change| (change.kind, change.sql.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_prune_undeclared_schema() {
+        let desired = SchemaDefinition::new()
+            .with_type(
+                TypeDefinition::new(TypeKind::Vertex, "Person")
+                    .property(PropertyDefinition::new("name", PropertyType::String).max(20)),
+            )
+            .prune(true);
+
+        let changes = diff(&live(), &desired).unwrap();
+
+        assert_eq!(
+            vec![
+                (ChangeKind::DropIndex, "DROP INDEX `Person[name]`"),
+                (ChangeKind::DropIndex, "DROP INDEX `Person[nickname]`"),
+                (
+                    ChangeKind::DropProperty,
+                    "DROP PROPERTY `Person`.`nickname`"
+                ),
+                (ChangeKind::DropType, "DROP TYPE `Legacy`"),
+            ],
+            changes
+                .iter()
+                .map(|change| (change.kind, change.sql.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_fail_on_incompatible_types() {
+        let desired = SchemaDefinition::new().with_type(
+            TypeDefinition::new(TypeKind::Vertex, "Person")
+                .property(PropertyDefinition::new("name", PropertyType::Integer)),
+        );
+
+        let error = diff(&live(), &desired).unwrap_err();
+
+        assert_eq!(
+            "Property Person.name is declared as INTEGER but exists as STRING",
+            error.to_string()
+        );
+    }
+}
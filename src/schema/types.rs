@@ -0,0 +1,178 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    error::InvalidIdentifier,
+    sql::{ident, sql_value},
+    ArcadeDBError, Database, ErrorResponse,
+};
+
+use super::{execute, idents};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TypeKind {
+    Vertex,
+    Edge,
+    Document,
+}
+
+impl TypeKind {
+    fn keyword(&self) -> &'static str {
+        match self {
+            TypeKind::Vertex => "VERTEX",
+            TypeKind::Edge => "EDGE",
+            TypeKind::Document => "DOCUMENT",
+        }
+    }
+}
+
+/// Builder for `CREATE <kind> TYPE`.
+pub struct CreateType {
+    kind: TypeKind,
+    name: String,
+    if_not_exists: bool,
+    supertypes: Vec<String>,
+    buckets: Vec<String>,
+    total_buckets: Option<u32>,
+}
+
+impl CreateType {
+    pub fn new(kind: TypeKind, name: impl Into<String>) -> Self {
+        CreateType {
+            kind,
+            name: name.into(),
+            if_not_exists: false,
+            supertypes: vec![],
+            buckets: vec![],
+            total_buckets: None,
+        }
+    }
+
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    pub fn extends(mut self, supertype: impl Into<String>) -> Self {
+        self.supertypes.push(supertype.into());
+        self
+    }
+
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.buckets.push(bucket.into());
+        self
+    }
+
+    pub fn total_buckets(mut self, buckets: u32) -> Self {
+        self.total_buckets = Some(buckets);
+        self
+    }
+
+    pub fn to_sql(&self) -> Result<String, InvalidIdentifier> {
+        let mut sql = format!("CREATE {} TYPE {}", self.kind.keyword(), ident(&self.name)?);
+        if self.if_not_exists {
+            sql.push_str(" IF NOT EXISTS");
+        }
+        if !self.supertypes.is_empty() {
+            sql.push_str(&format!(" EXTENDS {}", idents(&self.supertypes)?));
+        }
+        if !self.buckets.is_empty() {
+            sql.push_str(&format!(" BUCKET {}", idents(&self.buckets)?));
+        }
+        if let Some(total) = self.total_buckets {
+            sql.push_str(&format!(" BUCKETS {}", total));
+        }
+        Ok(sql)
+    }
+
+    pub async fn send(self, db: &Database) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        execute(db, self.to_sql()).await
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeAlteration {
+    Name(String),
+    AddSupertype(String),
+    RemoveSupertype(String),
+    AddBucket(String),
+    RemoveBucket(String),
+    Custom(String, Value),
+}
+
+/// Builder for `ALTER TYPE`.
+pub struct AlterType {
+    name: String,
+    alteration: TypeAlteration,
+}
+
+impl AlterType {
+    pub fn new(name: impl Into<String>, alteration: TypeAlteration) -> Self {
+        AlterType {
+            name: name.into(),
+            alteration,
+        }
+    }
+
+    pub fn to_sql(&self) -> Result<String, InvalidIdentifier> {
+        let clause = match &self.alteration {
+            TypeAlteration::Name(name) => format!("NAME {}", ident(name)?),
+            TypeAlteration::AddSupertype(name) => format!("SUPERTYPE +{}", ident(name)?),
+            TypeAlteration::RemoveSupertype(name) => format!("SUPERTYPE -{}", ident(name)?),
+            TypeAlteration::AddBucket(name) => format!("BUCKET +{}", ident(name)?),
+            TypeAlteration::RemoveBucket(name) => format!("BUCKET -{}", ident(name)?),
+            TypeAlteration::Custom(key, value) => {
+                format!("CUSTOM {} = {}", ident(key)?, sql_value(value))
+            }
+        };
+        Ok(format!("ALTER TYPE {} {}", ident(&self.name)?, clause))
+    }
+
+    pub async fn send(self, db: &Database) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        execute(db, self.to_sql()).await
+    }
+}
+
+/// Builder for `DROP TYPE`.
+pub struct DropType {
+    name: String,
+    if_exists: bool,
+    unsafe_drop: bool,
+}
+
+impl DropType {
+    pub fn new(name: impl Into<String>) -> Self {
+        DropType {
+            name: name.into(),
+            if_exists: false,
+            unsafe_drop: false,
+        }
+    }
+
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Drops the type even if it still contains records.
+    pub fn unsafe_drop(mut self) -> Self {
+        self.unsafe_drop = true;
+        self
+    }
+
+    pub fn to_sql(&self) -> Result<String, InvalidIdentifier> {
+        let mut sql = format!("DROP TYPE {}", ident(&self.name)?);
+        if self.if_exists {
+            sql.push_str(" IF EXISTS");
+        }
+        if self.unsafe_drop {
+            sql.push_str(" UNSAFE");
+        }
+        Ok(sql)
+    }
+
+    pub async fn send(self, db: &Database) -> Result<(), ArcadeDBError<ErrorResponse>> {
+        execute(db, self.to_sql()).await
+    }
+}
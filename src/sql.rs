@@ -0,0 +1,155 @@
+use serde_json::Value;
+
+use crate::error::InvalidIdentifier;
+
+/// Quotes `name` with backticks so it can be interpolated as a type, property,
+/// bucket or index name in an SQL statement.
+///
+/// ```rust
+/// use arcadedb_rs::ident;
+///
+/// assert_eq!("`my-type`", ident("my-type").unwrap());
+/// assert!(ident("").is_err());
+/// ```
+pub fn ident(name: &str) -> Result<String, InvalidIdentifier> {
+    validate(name)?;
+    Ok(format!("`{}`", name.replace('`', "\\`")))
+}
+
+/// Escapes `value` as a single-quoted SQL string literal.
+///
+/// Prefer binding values as statement parameters where the statement allows it.
+pub fn literal(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('\'');
+    for c in value.chars() {
+        match c {
+            '\'' => escaped.push_str("\\'"),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('\'');
+    escaped
+}
+
+/// Renders `value` as an SQL expression, escaping strings with [`literal`].
+pub(crate) fn sql_value(value: &Value) -> String {
+    match value {
+        Value::String(value) => literal(value),
+        Value::Array(values) => format!(
+            "[{}]",
+            values.iter().map(sql_value).collect::<Vec<_>>().join(", ")
+        ),
+        Value::Object(entries) => format!(
+            "{{{}}}",
+            entries
+                .iter()
+                .map(|(key, value)| format!("{}: {}", literal(key), sql_value(value)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        value => value.to_string(),
+    }
+}
+
+pub(crate) fn validate(name: &str) -> Result<&str, InvalidIdentifier> {
+    if name.is_empty() {
+        return Err(InvalidIdentifier::Empty);
+    }
+    match name.chars().find(|c| c.is_control() || *c == '\\') {
+        Some(c) => Err(InvalidIdentifier::InvalidCharacter(name.to_string(), c)),
+        None => Ok(name),
+    }
+}
+
+/// Checks that `name` is a bare token made of `[A-Za-z0-9_.-]`, for the server commands
+/// that split their arguments on spaces and cannot quote them.
+pub(crate) fn token(name: &str) -> Result<&str, InvalidIdentifier> {
+    if name.is_empty() {
+        return Err(InvalidIdentifier::Empty);
+    }
+    match name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-')))
+    {
+        Some(c) => Err(InvalidIdentifier::InvalidCharacter(name.to_string(), c)),
+        None => Ok(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::error::InvalidIdentifier;
+
+    use super::{ident, literal, sql_value, token};
+
+    #[test]
+    fn should_quote_identifiers() {
+        assert_eq!("`Person`", ident("Person").unwrap());
+        assert_eq!(
+            "`7c9e6679-7425-40de-944b-e07fc1f90ae7`",
+            ident("7c9e6679-7425-40de-944b-e07fc1f90ae7").unwrap()
+        );
+        assert_eq!("`a\\`b`", ident("a`b").unwrap());
+    }
+
+    #[test]
+    fn should_reject_invalid_identifiers() {
+        assert_eq!(Err(InvalidIdentifier::Empty), ident(""));
+        assert_eq!(
+            Err(InvalidIdentifier::InvalidCharacter(
+                "a\nb".to_string(),
+                '\n'
+            )),
+            ident("a\nb")
+        );
+        assert_eq!(
+            Err(InvalidIdentifier::InvalidCharacter("a\\".to_string(), '\\')),
+            ident("a\\")
+        );
+    }
+
+    #[test]
+    fn should_accept_only_bare_tokens() {
+        assert_eq!(
+            Ok("7c9e6679-7425-40de-944b-e07fc1f90ae7"),
+            token("7c9e6679-7425-40de-944b-e07fc1f90ae7")
+        );
+        assert_eq!(Ok("arcadedb.server.name"), token("arcadedb.server.name"));
+        assert_eq!(Err(InvalidIdentifier::Empty), token(""));
+        for (name, c) in [
+            ("a b", ' '),
+            ("a;b", ';'),
+            ("a,b", ','),
+            ("a=b", '='),
+            ("a`b", '`'),
+        ] {
+            assert_eq!(
+                Err(InvalidIdentifier::InvalidCharacter(name.to_string(), c)),
+                token(name)
+            );
+        }
+    }
+
+    #[test]
+    fn should_escape_literals() {
+        assert_eq!("'John'", literal("John"));
+        assert_eq!("'O\\'Reilly'", literal("O'Reilly"));
+        assert_eq!("'a\\\\b\\nc'", literal("a\\b\nc"));
+    }
+
+    #[test]
+    fn should_render_values() {
+        assert_eq!("1999", sql_value(&json!(1999)));
+        assert_eq!("true", sql_value(&json!(true)));
+        assert_eq!("null", sql_value(&json!(null)));
+        assert_eq!("['a', 'b\\'c']", sql_value(&json!(["a", "b'c"])));
+        assert_eq!("{'name': 'John'}", sql_value(&json!({ "name": "John" })));
+    }
+}
@@ -3,10 +3,12 @@ use serde::de::DeserializeOwned;
 use crate::{
     command::{Statement, StatementKind},
     db::Queryable,
+    gremlin::Traversal,
     protocol::{BeginRequest, CommitRequest, QueryCommand, RollbackRequest},
     ArcadeDBError, Database, ErrorResponse,
 };
 
+/// A server-side transaction.
 pub struct Transaction {
     session_id: String,
     db: Database,
@@ -29,12 +31,23 @@ impl Transaction {
         })
     }
 
+    /// Another handle on the same server-side transaction.
+    pub(crate) fn share(&self) -> Transaction {
+        Transaction {
+            session_id: self.session_id.clone(),
+            db: self.db.clone(),
+        }
+    }
+
     pub fn query<'a, 'b>(&'a self, stmt: &'b str) -> Statement<'a, 'b, Transaction> {
         Statement::new(self, stmt, StatementKind::Query)
     }
     pub fn command<'a, 'b>(&'a self, stmt: &'b str) -> Statement<'a, 'b, Transaction> {
         Statement::new(self, stmt, StatementKind::Command)
     }
+    pub fn gremlin<'a, 'b>(&'a self, traversal: &'b Traversal) -> Statement<'a, 'b, Transaction> {
+        traversal.statement(self)
+    }
 
     pub async fn commit(self) -> Result<(), ArcadeDBError<ErrorResponse>> {
         self.db
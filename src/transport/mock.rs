@@ -0,0 +1,86 @@
+//! A local HTTP server for offline tests of the transport.
+
+use std::sync::{Arc, Mutex};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+/// Answers each request with the next scripted `(status, body)` and records the
+/// `<method> <path> <authorization>` of every request it receives.
+pub(crate) struct MockServer {
+    pub(crate) url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockServer {
+    pub(crate) async fn start(responses: Vec<(u16, &'static str)>) -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let request = read_request(&mut stream).await;
+                recorded.lock().unwrap().push(request);
+                let response = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+                stream.shutdown().await.unwrap();
+            }
+        });
+
+        MockServer { url, requests }
+    }
+
+    pub(crate) fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn read_request(stream: &mut TcpStream) -> String {
+    let mut data = vec![];
+    let mut buffer = [0; 1024];
+    let headers_end = loop {
+        let read = stream.read(&mut buffer).await.unwrap();
+        assert!(read > 0, "Connection closed before the end of the request");
+        data.extend_from_slice(&buffer[..read]);
+        if let Some(end) = data.windows(4).position(|window| window == b"\r\n\r\n") {
+            break end + 4;
+        }
+    };
+    let head = String::from_utf8_lossy(&data[..headers_end]).to_string();
+    let header = |name: &str| {
+        head.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.eq_ignore_ascii_case(name)
+                .then(|| value.trim().to_string())
+        })
+    };
+
+    let length = header("content-length").map_or(0, |length| length.parse().unwrap());
+    while data.len() < headers_end + length {
+        let read = stream.read(&mut buffer).await.unwrap();
+        assert!(read > 0, "Connection closed before the end of the body");
+        data.extend_from_slice(&buffer[..read]);
+    }
+
+    let request_line = head.lines().next().unwrap_or_default();
+    let (method, path) = request_line
+        .split_once(' ')
+        .map(|(method, rest)| (method, rest.split(' ').next().unwrap_or_default()))
+        .unwrap_or_default();
+    format!(
+        "{} {} {}",
+        method,
+        path,
+        header("authorization").unwrap_or_default()
+    )
+}
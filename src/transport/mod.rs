@@ -4,6 +4,8 @@ use serde::de::DeserializeOwned;
 
 use crate::{options::ArcadeDBOptions, protocol::Request, ArcadeDBError};
 
+#[cfg(test)]
+pub(crate) mod mock;
 mod reqwest_impl;
 
 pub use reqwest_impl::ReqwestTransport;
@@ -1,21 +1,41 @@
 use std::{collections::HashMap, fmt::Display};
 
 use crate::{
-    options::ArcadeDBOptions,
+    options::{ArcadeDBOptions, Credentials},
     protocol::{Method, Request},
     ArcadeDBError, Auth,
 };
 use anyhow::Result;
-use reqwest::{Client, RequestBuilder};
-use serde::de::DeserializeOwned;
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{Map, Value};
+use tokio::sync::Mutex;
 
 use super::{ArcadeResponse, Transport};
 
 pub struct ReqwestTransport {
     opts: ArcadeDBOptions,
     client: Client,
+    session: Mutex<Option<Session>>,
 }
 
+/// A session token and the credentials it was opened with, so that a provider
+/// returning other credentials opens a new session.
+struct Session {
+    credentials: Credentials,
+    token: String,
+}
+
+#[derive(Deserialize)]
+struct LoginResponse {
+    token: String,
+}
+
+/// The error body of a failed login, read as the error of the request that needed it.
+#[derive(Debug, thiserror::Error)]
+#[error("Login failed: {0}")]
+struct LoginFailed(Value);
+
 #[async_trait::async_trait]
 impl Transport for ReqwestTransport {
     async fn send<T>(
@@ -27,8 +47,9 @@ impl Transport for ReqwestTransport {
         T::Payload: Send + Sync,
         T::ResponseError: DeserializeOwned + Display,
     {
-        self.prepare(request)
-            .send_with_response::<T::Response, T::ResponseError>()
+        let response = self.execute(&request).await.map_err(login_failed)?;
+        response
+            .with_response::<T::Response, T::ResponseError>()
             .await?
             .into_result()
             .map_err(ArcadeDBError::Error)
@@ -43,25 +64,43 @@ impl Transport for ReqwestTransport {
         T::Payload: Send + Sync,
         T::ResponseError: DeserializeOwned + Display,
     {
-        self.prepare(request)
-            .send_without_response::<T::ResponseError>()
-            .await
+        let response = self.execute(&request).await.map_err(login_failed)?;
+        response.without_response::<T::ResponseError>().await
     }
 
     fn new(opts: ArcadeDBOptions) -> Self {
         ReqwestTransport {
+            client: client(&opts),
             opts,
-            client: Client::new(),
+            session: Mutex::new(None),
         }
     }
 }
 
 impl ReqwestTransport {
-    fn prepare<T>(&self, request: T) -> RequestBuilder
+    /// Sends `request`, logging in again once if the session token was rejected.
+    async fn execute<T>(&self, request: &T) -> Result<Response>
+    where
+        T: Request + Send + Sync,
+        T::Payload: Send + Sync,
+    {
+        let (builder, token) = self.authenticated(self.prepare(request)).await?;
+        let response = builder.send().await?;
+
+        match token {
+            Some(token) if response.status() == StatusCode::UNAUTHORIZED => {
+                self.invalidate(&token).await;
+                let (builder, _) = self.authenticated(self.prepare(request)).await?;
+                Ok(builder.send().await?)
+            }
+            _ => Ok(response),
+        }
+    }
+
+    fn prepare<T>(&self, request: &T) -> RequestBuilder
     where
         T: Request + Send + Sync,
         T::Payload: Send + Sync,
-        T::ResponseError: DeserializeOwned,
     {
         let url = format!("{}{}", self.opts.url, request.path());
         let builder = match request.method() {
@@ -69,9 +108,7 @@ impl ReqwestTransport {
             Method::Post => self.client.post(url),
         };
 
-        let builder = builder
-            .authenticated(&self.opts.auth)
-            .with_custom_headers(request.metadata());
+        let builder = builder.with_custom_headers(request.metadata());
 
         if let Some(json) = request.payload() {
             builder.json(json)
@@ -79,32 +116,125 @@ impl ReqwestTransport {
             builder
         }
     }
+
+    /// Adds the credentials to `builder`, returning the session token used if any.
+    async fn authenticated(
+        &self,
+        builder: RequestBuilder,
+    ) -> Result<(RequestBuilder, Option<String>)> {
+        match &self.opts.auth.auth().await? {
+            Auth::NoAuth => Ok((builder, None)),
+            Auth::Basic(credentials) => Ok((
+                builder.basic_auth(&credentials.username, Some(credentials.password.expose())),
+                None,
+            )),
+            Auth::Bearer(token) => Ok((builder.bearer_auth(token.expose()), None)),
+            Auth::Session(credentials) => {
+                let token = self.session_token(credentials).await?;
+                Ok((builder.bearer_auth(&token), Some(token)))
+            }
+        }
+    }
+
+    async fn session_token(&self, credentials: &Credentials) -> Result<String> {
+        let mut session = self.session.lock().await;
+        match session.take() {
+            Some(current) if current.credentials == *credentials => {
+                let token = current.token.clone();
+                *session = Some(current);
+                return Ok(token);
+            }
+            Some(previous) => self.spawn_logout(previous.token),
+            None => {}
+        }
+        let response = self
+            .client
+            .post(format!("{}/api/v1/login", self.opts.url))
+            .basic_auth(&credentials.username, Some(credentials.password.expose()))
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(LoginFailed(error_value(response).await?).into());
+        }
+        let token = response.json::<LoginResponse>().await?.token;
+        *session = Some(Session {
+            credentials: credentials.clone(),
+            token: token.clone(),
+        });
+        Ok(token)
+    }
+
+    async fn invalidate(&self, token: &str) {
+        let mut session = self.session.lock().await;
+        if session.as_ref().map(|session| session.token.as_str()) == Some(token) {
+            *session = None;
+        }
+    }
+
+    /// Ends the current session, if any. The next request logs in again.
+    pub(crate) async fn logout(&self) -> Result<()> {
+        let session = self.session.lock().await.take();
+        if let Some(session) = session {
+            logout(self.client.clone(), self.opts.url.clone(), session.token).await?;
+        }
+        Ok(())
+    }
+
+    /// Logs out of `token` in the background when running within a Tokio runtime.
+    fn spawn_logout(&self, token: String) {
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            let (client, url) = (self.client.clone(), self.opts.url.clone());
+            handle.spawn(async move {
+                let _ = logout(client, url, token).await;
+            });
+        }
+    }
 }
 
-#[async_trait::async_trait]
-trait BuilderExt {
-    fn authenticated(self, auth: &Auth) -> Self;
-    async fn send_with_response<OK: DeserializeOwned, ERR: DeserializeOwned + Display>(
-        self,
-    ) -> Result<Either<ArcadeResponse<OK>, ERR>>;
+/// Turns a [`LoginFailed`] error into the error response of the request.
+fn login_failed<ERR: DeserializeOwned + Display>(err: anyhow::Error) -> ArcadeDBError<ERR> {
+    match err.downcast::<LoginFailed>() {
+        Ok(LoginFailed(body)) => match serde_json::from_value(body) {
+            Ok(response) => ArcadeDBError::Error(response),
+            Err(err) => anyhow::Error::from(err).into(),
+        },
+        Err(err) => err.into(),
+    }
+}
 
-    fn with_custom_headers(self, metadata: HashMap<String, String>) -> Self;
-    async fn send_without_response<ERR: DeserializeOwned + Display>(
-        self,
-    ) -> Result<ArcadeResponse<()>, ArcadeDBError<ERR>>;
+fn client(opts: &ArcadeDBOptions) -> Client {
+    let builder = Client::builder();
+    let builder = match opts.timeout {
+        Some(timeout) => builder.timeout(timeout),
+        None => builder,
+    };
+    builder.build().expect("Failed to build the HTTP client")
 }
 
-#[async_trait::async_trait]
-impl BuilderExt for RequestBuilder {
-    fn authenticated(self, auth: &Auth) -> Self {
-        match auth {
-            Auth::NoAuth => self,
-            Auth::Basic(credentials) => {
-                self.basic_auth(&credentials.username, Some(&credentials.password))
-            }
+async fn logout(client: Client, url: String, token: String) -> Result<()> {
+    client
+        .post(format!("{}/api/v1/logout", url))
+        .bearer_auth(token)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+
+/// Logs out of the session in the background when dropped within a Tokio runtime.
+impl Drop for ReqwestTransport {
+    fn drop(&mut self) {
+        if let Some(session) = self.session.get_mut().take() {
+            self.spawn_logout(session.token);
         }
     }
+}
+
+trait BuilderExt {
+    fn with_custom_headers(self, metadata: HashMap<String, String>) -> Self;
+}
 
+impl BuilderExt for RequestBuilder {
     fn with_custom_headers(self, metadata: HashMap<String, String>) -> Self {
         let mut this = self;
         for (k, v) in metadata.iter() {
@@ -112,47 +242,81 @@ impl BuilderExt for RequestBuilder {
         }
         this
     }
-    async fn send_with_response<OK: DeserializeOwned, ERR: DeserializeOwned + Display>(
+}
+
+#[async_trait::async_trait]
+trait ResponseExt {
+    async fn with_response<OK: DeserializeOwned, ERR: DeserializeOwned + Display>(
         self,
-    ) -> Result<Either<ArcadeResponse<OK>, ERR>> {
-        let response = self.send().await?;
+    ) -> Result<Either<ArcadeResponse<OK>, ERR>>;
 
-        let headers: HashMap<String, String> = response
-            .headers()
-            .iter()
-            .map(|(name, value)| (name.to_string(), value.to_str().unwrap().to_string()))
-            .collect();
+    async fn without_response<ERR: DeserializeOwned + Display>(
+        self,
+    ) -> Result<ArcadeResponse<()>, ArcadeDBError<ERR>>;
+}
+
+#[async_trait::async_trait]
+impl ResponseExt for Response {
+    async fn with_response<OK: DeserializeOwned, ERR: DeserializeOwned + Display>(
+        self,
+    ) -> Result<Either<ArcadeResponse<OK>, ERR>> {
+        let headers = headers(&self);
 
-        if response.status().is_success() {
+        if self.status().is_success() {
             Ok(Either::Left(ArcadeResponse::new(
-                response.json().await?,
+                self.json().await?,
                 headers,
             )))
         } else {
-            Ok(Either::Right(response.json().await?))
+            Ok(Either::Right(error_body(self).await?))
         }
     }
-    async fn send_without_response<ERR: DeserializeOwned + Display>(
+
+    async fn without_response<ERR: DeserializeOwned + Display>(
         self,
     ) -> Result<ArcadeResponse<()>, ArcadeDBError<ERR>> {
-        let response = self.send().await.map_err(anyhow::Error::from)?;
-
-        let headers: HashMap<String, String> = response
-            .headers()
-            .iter()
-            .map(|(name, value)| (name.to_string(), value.to_str().unwrap().to_string()))
-            .collect();
+        let headers = headers(&self);
 
-        if response.status().is_success() {
+        if self.status().is_success() {
             Ok(ArcadeResponse::new((), headers))
         } else {
-            Err(ArcadeDBError::Error(
-                response.json().await.map_err(anyhow::Error::from)?,
-            ))
+            Err(ArcadeDBError::Error(error_body(self).await?))
         }
     }
 }
 
+/// Reads the body of an error response along with its HTTP `status`.
+async fn error_body<ERR: DeserializeOwned>(response: Response) -> Result<ERR> {
+    Ok(serde_json::from_value(error_value(response).await?)?)
+}
+
+/// The JSON body of an error response with its HTTP `status`. Bodies that are not JSON
+/// objects, like the empty body of a 401, become the `error` message.
+async fn error_value(response: Response) -> Result<Value> {
+    let status = response.status();
+    let text = response.text().await?;
+    let mut body = match serde_json::from_str::<Value>(&text) {
+        Ok(Value::Object(body)) => body,
+        _ => {
+            let error = match text.trim() {
+                "" => status.canonical_reason().unwrap_or_default(),
+                text => text,
+            };
+            Map::from_iter([(String::from("error"), Value::from(error))])
+        }
+    };
+    body.insert(String::from("status"), Value::from(status.as_u16()));
+    Ok(Value::Object(body))
+}
+
+fn headers(response: &Response) -> HashMap<String, String> {
+    response
+        .headers()
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_str().unwrap().to_string()))
+        .collect()
+}
+
 pub enum Either<L, R> {
     Left(L),
     Right(R),
@@ -166,3 +330,82 @@ impl<L, R> Either<L, R> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{transport::mock::MockServer, ArcadeDB, ArcadeDBError, Auth, ErrorResponse};
+
+    async fn client(server: &MockServer) -> ArcadeDB {
+        ArcadeDB::builder()
+            .auth(Auth::session("root", "playwithdata"))
+            .build(server.url.clone())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_log_in_again_once_when_the_session_expires() {
+        let server = MockServer::start(vec![
+            (200, r#"{ "token": "AU-1" }"#),
+            (401, ""),
+            (200, r#"{ "token": "AU-2" }"#),
+            (204, ""),
+        ])
+        .await;
+
+        client(&server).await.ping().await.unwrap();
+
+        let basic = "Basic cm9vdDpwbGF5d2l0aGRhdGE=";
+        assert_eq!(
+            vec![
+                format!("POST /api/v1/login {}", basic),
+                String::from("GET /api/v1/ready Bearer AU-1"),
+                format!("POST /api/v1/login {}", basic),
+                String::from("GET /api/v1/ready Bearer AU-2"),
+            ],
+            server.requests()
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_twice() {
+        let server = MockServer::start(vec![
+            (200, r#"{ "token": "AU-1" }"#),
+            (401, ""),
+            (200, r#"{ "token": "AU-2" }"#),
+            (401, ""),
+        ])
+        .await;
+
+        let error = client(&server).await.ping().await.unwrap_err();
+
+        assert!(matches!(
+            error,
+            ArcadeDBError::Error(ErrorResponse {
+                status: Some(401),
+                ..
+            })
+        ));
+        assert_eq!(4, server.requests().len());
+    }
+
+    #[tokio::test]
+    async fn should_return_the_login_error() {
+        let server = MockServer::start(vec![(
+            403,
+            r#"{ "error": "User not authorized", "detail": "root" }"#,
+        )])
+        .await;
+
+        let error = client(&server).await.ping().await.unwrap_err();
+
+        match error {
+            ArcadeDBError::Error(response) => {
+                assert_eq!("User not authorized", response.error);
+                assert_eq!(Some("root"), response.detail.as_deref());
+                assert!(response.is_forbidden());
+            }
+            ArcadeDBError::Generic(err) => panic!("Expected an error response, got {}", err),
+        }
+    }
+}
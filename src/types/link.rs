@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{ArcadeDBError, Database, ErrorResponse, RecordID};
+
+/// A relationship field that holds either the [`RecordID`] of the linked record or,
+/// when the query expanded it, the record itself along with its `@rid`.
+///
+/// ```rust
+/// use arcadedb_rs::{Link, RecordID};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Person {
+///     name: String,
+/// }
+///
+/// let link: Link<Person> = serde_json::from_str(r##""#12:3""##).unwrap();
+/// assert_eq!(RecordID::new(12, 3), link.id());
+///
+/// let link: Link<Person> =
+///     serde_json::from_str(r##"{ "@rid": "#12:3", "name": "Keanu" }"##).unwrap();
+/// assert_eq!(RecordID::new(12, 3), link.id());
+/// assert_eq!("Keanu", link.record().unwrap().name);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Link<T> {
+    Id(RecordID),
+    Record(RecordID, T),
+}
+
+impl<T> Link<T> {
+    /// The id of the linked record, expanded or not.
+    pub fn id(&self) -> RecordID {
+        match self {
+            Link::Id(rid) | Link::Record(rid, _) => *rid,
+        }
+    }
+
+    pub fn record(&self) -> Option<&T> {
+        match self {
+            Link::Id(_) => None,
+            Link::Record(_, record) => Some(record),
+        }
+    }
+
+    pub fn into_record(self) -> Option<T> {
+        match self {
+            Link::Id(_) => None,
+            Link::Record(_, record) => Some(record),
+        }
+    }
+
+    pub fn is_expanded(&self) -> bool {
+        matches!(self, Link::Record(..))
+    }
+}
+
+impl<T: DeserializeOwned + Send + Sync> Link<T> {
+    /// Loads the linked record if the link has not been expanded yet and returns it,
+    /// keeping its [`RecordID`].
+    pub async fn resolve(&mut self, db: &Database) -> Result<&T, ArcadeDBError<ErrorResponse>> {
+        Link::resolve_all(std::iter::once(&mut *self), db).await?;
+        match self {
+            Link::Record(_, record) => Ok(record),
+            Link::Id(rid) => Err(anyhow::anyhow!("Record {} not found", rid).into()),
+        }
+    }
+
+    /// Loads all the links not expanded yet with a single `select from [<rids>]`.
+    ///
+    /// ```rust,no_run
+    /// # use arcadedb_rs::{Database, Link};
+    /// # use serde::Deserialize;
+    /// # #[derive(Deserialize)]
+    /// # struct Movie { title: String }
+    /// #[derive(Deserialize)]
+    /// struct Actor {
+    ///     movies: Vec<Link<Movie>>,
+    /// }
+    ///
+    /// # async fn run(db: Database) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut actors = db
+    ///     .query("select out('ACTED_IN') as movies from Person")
+    ///     .send::<Actor>()
+    ///     .await?;
+    ///
+    /// Link::resolve_all(actors.iter_mut().flat_map(|actor| &mut actor.movies), &db).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve_all<'l, I>(
+        links: I,
+        db: &Database,
+    ) -> Result<(), ArcadeDBError<ErrorResponse>>
+    where
+        I: IntoIterator<Item = &'l mut Link<T>>,
+        T: 'l,
+    {
+        let mut pending = links
+            .into_iter()
+            .filter(|link| !link.is_expanded())
+            .collect::<Vec<_>>();
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let mut rids = pending.iter().map(|link| link.id()).collect::<Vec<_>>();
+        rids.sort();
+        rids.dedup();
+        let sql = format!(
+            "select from [{}]",
+            rids.iter()
+                .map(RecordID::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        let records = db
+            .query(&sql)
+            .send::<Value>()
+            .await?
+            .into_iter()
+            .map(|record| {
+                let rid = serde_json::from_value::<RecordID>(record.clone())?;
+                Ok((rid, record))
+            })
+            .collect::<Result<HashMap<_, _>, serde_json::Error>>()
+            .map_err(anyhow::Error::from)?;
+
+        for link in pending.iter_mut() {
+            let rid = link.id();
+            let record = records
+                .get(&rid)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("Record {} not found", rid))?;
+            let record = serde_json::from_value(record).map_err(anyhow::Error::from)?;
+            **link = Link::Record(rid, record);
+        }
+        Ok(())
+    }
+}
+
+impl<T> From<RecordID> for Link<T> {
+    fn from(rid: RecordID) -> Self {
+        Link::Id(rid)
+    }
+}
+
+/// Links are always written as their [`RecordID`], so that storing a resolved record
+/// keeps a link rather than embedding a copy of the record.
+impl<T> Serialize for Link<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.id().serialize(serializer)
+    }
+}
+
+/// A string is an id, an object is an expanded record unless it only carries metadata
+/// like `@rid` and `@type`, which happens when the record could not be expanded.
+/// Expanded records must carry their `@rid`.
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Link<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        match &value {
+            Value::String(rid) => rid.parse().map(Link::Id).map_err(serde::de::Error::custom),
+            Value::Object(entries) if entries.keys().all(|key| key.starts_with('@')) => {
+                serde_json::from_value(value)
+                    .map(Link::Id)
+                    .map_err(serde::de::Error::custom)
+            }
+            Value::Object(entries) => {
+                let rid = entries
+                    .get("@rid")
+                    .ok_or_else(|| serde::de::Error::missing_field("@rid"))
+                    .and_then(|rid| RecordID::deserialize(rid).map_err(serde::de::Error::custom))?;
+                serde_json::from_value(value)
+                    .map(|record| Link::Record(rid, record))
+                    .map_err(serde::de::Error::custom)
+            }
+            _ => Err(serde::de::Error::custom(format!(
+                "Expected a RecordID or a record, found {}",
+                value
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::{Link, RecordID};
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Movie {
+        title: String,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Person {
+        name: String,
+        movies: Vec<Link<Movie>>,
+        best: Option<Link<Movie>>,
+    }
+
+    #[test]
+    fn should_deserialize_links() {
+        let person: Person = serde_json::from_value(json!({
+            "name": "Keanu",
+            "movies": ["#12:3", { "@rid": "#12:4", "@type": "Movie", "title": "Heat" }],
+            "best": { "@rid": "#12:3", "@type": "Movie" }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                Link::Id(RecordID::new(12, 3)),
+                Link::Record(
+                    RecordID::new(12, 4),
+                    Movie {
+                        title: "Heat".to_string()
+                    }
+                )
+            ],
+            person.movies
+        );
+        assert_eq!(Some(Link::Id(RecordID::new(12, 3))), person.best);
+    }
+
+    #[test]
+    fn should_serialize_links_as_ids() {
+        let id = Link::<Movie>::Id(RecordID::new(12, 3));
+        let record = Link::Record(
+            RecordID::new(12, 4),
+            Movie {
+                title: "Heat".to_string(),
+            },
+        );
+
+        assert_eq!(json!("#12:3"), serde_json::to_value(&id).unwrap());
+        assert_eq!(json!("#12:4"), serde_json::to_value(&record).unwrap());
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_invalid_links() {
+        assert!(serde_json::from_value::<Link<Movie>>(json!("12:3")).is_err());
+        assert!(serde_json::from_value::<Link<Movie>>(json!({ "name": "Heat" })).is_err());
+        assert!(serde_json::from_value::<Link<Movie>>(json!({ "title": "Heat" })).is_err());
+        assert!(serde_json::from_value::<Link<Movie>>(json!(12)).is_err());
+    }
+}
@@ -1 +1,5 @@
+pub mod link;
 pub mod rid;
+pub mod typed;
+pub mod value;
+pub mod version;
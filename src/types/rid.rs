@@ -1,6 +1,23 @@
-use serde::{de::Visitor, Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
 
-#[derive(Debug, PartialEq, Eq)]
+use serde::{
+    de::{IgnoredAny, MapAccess, Visitor},
+    Deserialize, Serialize,
+};
+use serde_json::Value;
+
+use crate::error::InvalidRecordID;
+
+/// The identifier of a record, `#<bucket-identifier>:<record-position>`.
+///
+/// ```rust
+/// use arcadedb_rs::RecordID;
+///
+/// let rid: RecordID = "#12:3".parse().unwrap();
+/// assert_eq!(12, rid.bucket_id());
+/// assert_eq!("#12:3", rid.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct RecordID {
     bucket_id: i32,
     record_position: i64,
@@ -13,6 +30,63 @@ impl RecordID {
             record_position,
         }
     }
+
+    /// The `#-1:-1` identifier of a record that has no identity.
+    pub fn null() -> Self {
+        Self::new(-1, -1)
+    }
+
+    pub fn bucket_id(&self) -> i32 {
+        self.bucket_id
+    }
+
+    pub fn record_position(&self) -> i64 {
+        self.record_position
+    }
+
+    pub fn is_null(&self) -> bool {
+        *self == Self::null()
+    }
+
+    /// Returns `true` for the negative positions assigned to records not yet persisted.
+    pub fn is_temporary(&self) -> bool {
+        !self.is_null() && self.record_position < 0
+    }
+}
+
+impl Display for RecordID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}:{}", self.bucket_id, self.record_position)
+    }
+}
+
+impl FromStr for RecordID {
+    type Err = InvalidRecordID;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (bucket_id, record_position) = s
+            .strip_prefix('#')
+            .and_then(|rid| rid.split_once(':'))
+            .filter(|(bucket_id, record_position)| {
+                !bucket_id.is_empty()
+                    && !record_position.is_empty()
+                    && !record_position.contains(':')
+            })
+            .ok_or(InvalidRecordID::Format)?;
+        Ok(RecordID::new(bucket_id.parse()?, record_position.parse()?))
+    }
+}
+
+impl From<RecordID> for Value {
+    fn from(rid: RecordID) -> Self {
+        Value::String(rid.to_string())
+    }
+}
+
+impl From<&RecordID> for Value {
+    fn from(rid: &RecordID) -> Self {
+        Value::String(rid.to_string())
+    }
 }
 
 impl Serialize for RecordID {
@@ -20,16 +94,21 @@ impl Serialize for RecordID {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&format!("#{}:{}", self.bucket_id, self.record_position))
+        serializer.collect_str(self)
     }
 }
 
+/// Deserializes from the `#b:p` string as well as from an embedded or linked record
+/// object carrying its identity in `@rid`.
+///
+/// Telling the two apart uses `deserialize_any` rather than `deserialize_str`, so a
+/// `RecordID` can only be read from self-describing formats like JSON.
 impl<'de> Deserialize<'de> for RecordID {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_str(RecordIDVisitor)
+        deserializer.deserialize_any(RecordIDVisitor)
     }
 }
 
@@ -49,21 +128,22 @@ impl<'v> Visitor<'v> for RecordIDVisitor {
     where
         E: serde::de::Error,
     {
-        match v
-            .split_terminator(&['#', ':'])
-            .collect::<Vec<&str>>()
-            .as_slice()
-        {
-            ["", bucket_id, record_position] => {
-                let bucket = bucket_id.parse().map_err(E::custom)?;
-                let position = record_position.parse().map_err(E::custom)?;
-                Ok(RecordID::new(bucket, position))
-            }
+        v.parse().map_err(E::custom)
+    }
 
-            _ => Err(E::custom(
-                "Invalid RecordID, expected format #<bucket-identifier>:<record-position>",
-            )),
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'v>,
+    {
+        let mut rid = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "@rid" {
+                rid = Some(map.next_value::<RecordID>()?);
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
         }
+        rid.ok_or_else(|| serde::de::Error::missing_field("@rid"))
     }
 }
 
@@ -107,6 +187,12 @@ mod tests {
             "Invalid RecordID, expected format #<bucket-identifier>:<record-position>",
         );
     }
+    #[test]
+    fn should_fail_to_parse_rid_with_trailing_input() {
+        for rid in ["#1:2:", "#1:2:3", "#1:2#", "#1:2 ", "##1:2", "#:2"] {
+            assert!(rid.parse::<RecordID>().is_err(), "{}", rid);
+        }
+    }
 
     fn assert_token_error(rid: &'static str, error: &str) {
         serde_test::assert_de_tokens_error::<Readable<RecordID>>(&[Token::Str(rid)], error);
@@ -150,4 +236,48 @@ mod tests {
             deserialized.to_string()
         );
     }
+
+    #[test]
+    fn should_parse_display_and_order_rids() {
+        let rid: RecordID = "#12:3".parse().unwrap();
+
+        assert_eq!(RecordID::new(12, 3), rid);
+        assert_eq!((12, 3), (rid.bucket_id(), rid.record_position()));
+        assert_eq!("#12:3", rid.to_string());
+        assert!(RecordID::new(12, 3) < RecordID::new(12, 10));
+        assert!(RecordID::new(2, 30) < RecordID::new(12, 3));
+        assert!("12:3".parse::<RecordID>().is_err());
+        assert_eq!(json!("#12:3"), serde_json::Value::from(rid));
+
+        let mut names = std::collections::HashMap::new();
+        names.insert(rid, "The Matrix");
+        assert_eq!(Some(&"The Matrix"), names.get(&RecordID::new(12, 3)));
+    }
+
+    #[test]
+    fn should_detect_null_and_temporary_rids() {
+        assert!(RecordID::null().is_null());
+        assert!(!RecordID::null().is_temporary());
+        assert!(RecordID::new(3, -2).is_temporary());
+        assert!(!RecordID::new(3, 2).is_temporary());
+    }
+
+    #[test]
+    fn should_deserialize_rids_from_records() {
+        assert_eq!(
+            RecordID::new(1, 2),
+            serde_json::from_value(json!({ "@rid": "#1:2", "@type": "Movie", "title": "Heat" }))
+                .unwrap()
+        );
+        assert_eq!(
+            vec![RecordID::new(1, 2), RecordID::new(1, 3)],
+            serde_json::from_value::<Vec<RecordID>>(json!(["#1:2", { "@rid": "#1:3" }])).unwrap()
+        );
+        assert_eq!(
+            "missing field `@rid`",
+            serde_json::from_value::<RecordID>(json!({ "title": "Heat" }))
+                .unwrap_err()
+                .to_string()
+        );
+    }
 }
@@ -0,0 +1,219 @@
+use serde::{de::Error, Deserialize, Deserializer};
+use serde_json::Value;
+
+/// A record whose Rust type is selected by its `@type`, typically an enum with one
+/// variant per subtype returned by a query over their supertype.
+///
+/// The [`typed_record!`](crate::typed_record) macro declares such an enum and
+/// implements both this trait and [`Deserialize`] with [`deserialize_typed`]:
+///
+/// ```rust
+/// use arcadedb_rs::typed_record;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize, Debug)]
+/// struct Actor {
+///     name: String,
+/// }
+///
+/// #[derive(Deserialize, Debug)]
+/// struct Director {
+///     name: String,
+/// }
+///
+/// typed_record! {
+///     #[derive(Debug)]
+///     enum Person {
+///         Actor(Actor),
+///         Director(Director) = "FilmDirector",
+///     }
+/// }
+///
+/// let person: Person =
+///     serde_json::from_str(r#"{ "@type": "FilmDirector", "name": "Lana" }"#).unwrap();
+/// assert_eq!("FilmDirector", person.type_name());
+///
+/// let error = serde_json::from_str::<Person>(r#"{ "@type": "Producer" }"#).unwrap_err();
+/// assert_eq!(
+///     "Unknown @type \"Producer\", expected one of Actor, FilmDirector",
+///     error.to_string()
+/// );
+/// ```
+pub trait TypedRecord: Sized {
+    /// The accepted `@type` names.
+    fn type_names() -> &'static [&'static str];
+
+    /// Deserializes `record`, whose `@type` is `type_name`, one of
+    /// [`type_names`](TypedRecord::type_names).
+    fn from_record(type_name: &str, record: Value) -> Result<Self, serde_json::Error>;
+}
+
+/// Deserializes a [`TypedRecord`] by dispatching on the `@type` field.
+pub fn deserialize_typed<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: TypedRecord,
+    D: Deserializer<'de>,
+{
+    let record = Value::deserialize(deserializer)?;
+    let expected = T::type_names().join(", ");
+    let type_name = match record.get("@type") {
+        Some(Value::String(type_name)) => type_name.clone(),
+        _ => {
+            return Err(D::Error::custom(format!(
+                "Missing @type, expected one of {}",
+                expected
+            )))
+        }
+    };
+    if !T::type_names().contains(&type_name.as_str()) {
+        return Err(D::Error::custom(format!(
+            "Unknown @type {:?}, expected one of {}",
+            type_name, expected
+        )));
+    }
+    T::from_record(&type_name, record).map_err(D::Error::custom)
+}
+
+/// Declares an enum deserialized by dispatching on `@type`, see [`TypedRecord`].
+///
+/// Each variant wraps the type of one record type, named after the variant unless
+/// given with `= "TypeName"`.
+#[macro_export]
+macro_rules! typed_record {
+    (@name $variant:ident) => {
+        stringify!($variant)
+    };
+    (@name $variant:ident, $type_name:literal) => {
+        $type_name
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($variant:ident($ty:ty) $(= $type_name:literal)?),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $($variant($ty)),*
+        }
+
+        impl $name {
+            /// The `@type` of the record.
+            #[allow(dead_code)]
+            pub fn type_name(&self) -> &'static str {
+                match self {
+                    $(
+                        $name::$variant(_) => {
+                            $crate::typed_record!(@name $variant $(, $type_name)?)
+                        }
+                    )*
+                }
+            }
+        }
+
+        impl $crate::TypedRecord for $name {
+            fn type_names() -> &'static [&'static str] {
+                &[$($crate::typed_record!(@name $variant $(, $type_name)?)),*]
+            }
+
+            fn from_record(
+                type_name: &str,
+                record: $crate::__private::serde_json::Value,
+            ) -> Result<Self, $crate::__private::serde_json::Error> {
+                $(
+                    if type_name == $crate::typed_record!(@name $variant $(, $type_name)?) {
+                        return $crate::__private::serde_json::from_value(record)
+                            .map($name::$variant);
+                    }
+                )*
+                use $crate::__private::serde::de::Error;
+                Err($crate::__private::serde_json::Error::custom(format!(
+                    "Unknown @type {:?}",
+                    type_name
+                )))
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                $crate::deserialize_typed(deserializer)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::TypedRecord;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Actor {
+        name: String,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Director {
+        name: String,
+        movies: u32,
+    }
+
+    crate::typed_record! {
+        #[derive(Debug, PartialEq)]
+        enum Person {
+            Actor(Actor),
+            Director(Director) = "FilmDirector",
+        }
+    }
+
+    #[test]
+    fn should_dispatch_on_type() {
+        let people: Vec<Person> = serde_json::from_value(json!([
+            { "@rid": "#1:0", "@type": "Actor", "name": "Keanu" },
+            { "@rid": "#2:0", "@type": "FilmDirector", "name": "Lana", "movies": 4 }
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                Person::Actor(Actor {
+                    name: "Keanu".to_string()
+                }),
+                Person::Director(Director {
+                    name: "Lana".to_string(),
+                    movies: 4
+                })
+            ],
+            people
+        );
+        assert_eq!(&["Actor", "FilmDirector"], Person::type_names());
+        assert_eq!("Actor", people[0].type_name());
+    }
+
+    #[test]
+    fn should_fail_on_unknown_or_missing_type() {
+        assert_eq!(
+            "Unknown @type \"Director\", expected one of Actor, FilmDirector",
+            serde_json::from_value::<Person>(json!({ "@type": "Director", "name": "Lana" }))
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "Missing @type, expected one of Actor, FilmDirector",
+            serde_json::from_value::<Person>(json!({ "name": "Lana" }))
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "missing field `movies`",
+            serde_json::from_value::<Person>(json!({ "@type": "FilmDirector", "name": "Lana" }))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
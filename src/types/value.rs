@@ -0,0 +1,437 @@
+use std::collections::HashMap;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{ser::SerializeMap, Deserialize, Serialize};
+use serde_json::{Number, Value};
+
+use crate::RecordID;
+
+/// A value with variants for the ArcadeDB types, to build parameters and read results.
+///
+/// The JSON returned by the server does not carry the property types: dates and
+/// datetimes are epoch milliseconds, decimals are numbers, binaries are base64 strings
+/// and links are strings like `#12:3`. Deserializing into `ArcadeValue` therefore never
+/// produces the `Date`, `DateTime`, `Binary` or `Link` variants. Numbers become `Decimal`
+/// only when `f64` would not print them back as received: integers beyond `i64`, and,
+/// with the `arbitrary_precision` feature, decimals with more digits than `f64` holds.
+/// Without the feature `serde_json` parses decimals as `f64` first. Only embedded documents,
+/// told apart from maps by their `@type` or `@rid`, are recognized; the accessors like
+/// [`as_date`](ArcadeValue::as_date), [`as_binary`](ArcadeValue::as_binary) or
+/// [`as_link`](ArcadeValue::as_link) interpret the other values on demand.
+///
+/// Those variants are for writing: converting into [`serde_json::Value`] lets an
+/// `ArcadeValue` be bound with `Statement::param`, and `Vec<u8>` and `&[u8]` convert
+/// into [`ArcadeValue::Binary`].
+///
+/// ```rust
+/// use arcadedb_rs::{ArcadeValue, RecordID};
+///
+/// let value: ArcadeValue = serde_json::from_str(r##""#12:3""##).unwrap();
+/// assert_eq!(ArcadeValue::String(String::from("#12:3")), value);
+/// assert_eq!(Some(RecordID::new(12, 3)), value.as_link());
+///
+/// let value = ArcadeValue::Decimal(String::from("12.300000000000000001"));
+/// assert_eq!(serde_json::json!("12.300000000000000001"), serde_json::Value::from(value));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArcadeValue {
+    Null,
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    /// A decimal kept in its textual form to preserve its precision when written.
+    Decimal(String),
+    String(String),
+    /// A date as epoch milliseconds at midnight.
+    Date(i64),
+    /// A datetime as epoch milliseconds.
+    DateTime(i64),
+    Binary(Vec<u8>),
+    Link(RecordID),
+    /// A document embedded in a record, carrying its `@type`.
+    Embedded(HashMap<String, ArcadeValue>),
+    List(Vec<ArcadeValue>),
+    Map(HashMap<String, ArcadeValue>),
+}
+
+impl ArcadeValue {
+    pub fn is_null(&self) -> bool {
+        matches!(self, ArcadeValue::Null)
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            ArcadeValue::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            ArcadeValue::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            ArcadeValue::Integer(value) => Some(*value as f64),
+            ArcadeValue::Float(value) => Some(*value),
+            ArcadeValue::Decimal(value) => value.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            ArcadeValue::String(value) | ArcadeValue::Decimal(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The textual form of a decimal, also accepted from numbers and strings.
+    pub fn as_decimal(&self) -> Option<String> {
+        match self {
+            ArcadeValue::Decimal(value) | ArcadeValue::String(value) => Some(value.clone()),
+            ArcadeValue::Integer(value) => Some(value.to_string()),
+            ArcadeValue::Float(value) => Some(value.to_string()),
+            _ => None,
+        }
+    }
+
+    /// The epoch milliseconds of a date or datetime, also accepted from integers.
+    pub fn as_date(&self) -> Option<i64> {
+        match self {
+            ArcadeValue::Date(millis)
+            | ArcadeValue::DateTime(millis)
+            | ArcadeValue::Integer(millis) => Some(*millis),
+            _ => None,
+        }
+    }
+
+    /// The bytes of a binary, also decoded from base64 strings.
+    pub fn as_binary(&self) -> Option<Vec<u8>> {
+        match self {
+            ArcadeValue::Binary(bytes) => Some(bytes.clone()),
+            ArcadeValue::String(value) => STANDARD.decode(value).ok(),
+            _ => None,
+        }
+    }
+
+    /// The id of a link or of an embedded record, also parsed from strings.
+    pub fn as_link(&self) -> Option<RecordID> {
+        match self {
+            ArcadeValue::Link(rid) => Some(*rid),
+            ArcadeValue::String(value) => value.parse().ok(),
+            ArcadeValue::Embedded(fields) => fields.get("@rid").and_then(ArcadeValue::as_link),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[ArcadeValue]> {
+        match self {
+            ArcadeValue::List(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn as_map(&self) -> Option<&HashMap<String, ArcadeValue>> {
+        match self {
+            ArcadeValue::Embedded(entries) | ArcadeValue::Map(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    /// The value of `key` in an embedded document or map.
+    pub fn get(&self, key: &str) -> Option<&ArcadeValue> {
+        self.as_map().and_then(|entries| entries.get(key))
+    }
+}
+
+impl From<Value> for ArcadeValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => ArcadeValue::Null,
+            Value::Bool(value) => ArcadeValue::Boolean(value),
+            Value::Number(number) => match (number.as_i64(), number.is_u64()) {
+                (Some(value), _) => ArcadeValue::Integer(value),
+                // Integers beyond i64 keep their digits rather than rounding to f64.
+                (None, true) => ArcadeValue::Decimal(number.to_string()),
+                // The text only differs from the f64 one with `arbitrary_precision`.
+                (None, false) => match number.as_f64().and_then(Number::from_f64) {
+                    Some(float) if float == number => ArcadeValue::Float(float.as_f64().unwrap()),
+                    _ => ArcadeValue::Decimal(number.to_string()),
+                },
+            },
+            Value::String(value) => ArcadeValue::String(value),
+            Value::Array(values) => {
+                ArcadeValue::List(values.into_iter().map(ArcadeValue::from).collect())
+            }
+            Value::Object(entries) => {
+                let embedded = entries.contains_key("@type") || entries.contains_key("@rid");
+                let entries = entries
+                    .into_iter()
+                    .map(|(key, value)| (key, ArcadeValue::from(value)))
+                    .collect();
+                if embedded {
+                    ArcadeValue::Embedded(entries)
+                } else {
+                    ArcadeValue::Map(entries)
+                }
+            }
+        }
+    }
+}
+
+impl From<ArcadeValue> for Value {
+    fn from(value: ArcadeValue) -> Self {
+        match value {
+            ArcadeValue::Null => Value::Null,
+            ArcadeValue::Boolean(value) => Value::Bool(value),
+            ArcadeValue::Integer(value)
+            | ArcadeValue::Date(value)
+            | ArcadeValue::DateTime(value) => Value::Number(value.into()),
+            ArcadeValue::Float(value) => Number::from_f64(value)
+                .map(Value::Number)
+                .unwrap_or(Value::Null),
+            ArcadeValue::Decimal(value) | ArcadeValue::String(value) => Value::String(value),
+            ArcadeValue::Binary(bytes) => Value::String(STANDARD.encode(bytes)),
+            ArcadeValue::Link(rid) => rid.into(),
+            ArcadeValue::List(values) => {
+                Value::Array(values.into_iter().map(Value::from).collect())
+            }
+            ArcadeValue::Embedded(entries) | ArcadeValue::Map(entries) => Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, Value::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl Serialize for ArcadeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            ArcadeValue::Null => serializer.serialize_none(),
+            ArcadeValue::Boolean(value) => serializer.serialize_bool(*value),
+            ArcadeValue::Integer(value)
+            | ArcadeValue::Date(value)
+            | ArcadeValue::DateTime(value) => serializer.serialize_i64(*value),
+            ArcadeValue::Float(value) if value.is_finite() => serializer.serialize_f64(*value),
+            ArcadeValue::Float(_) => serializer.serialize_none(),
+            ArcadeValue::Decimal(value) | ArcadeValue::String(value) => {
+                serializer.serialize_str(value)
+            }
+            ArcadeValue::Binary(bytes) => serializer.serialize_str(&STANDARD.encode(bytes)),
+            ArcadeValue::Link(rid) => rid.serialize(serializer),
+            ArcadeValue::List(values) => serializer.collect_seq(values),
+            ArcadeValue::Embedded(entries) | ArcadeValue::Map(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ArcadeValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Value::deserialize(deserializer).map(ArcadeValue::from)
+    }
+}
+
+macro_rules! from_primitive {
+    ($variant:ident, $($ty:ty),*) => {
+        $(
+            impl From<$ty> for ArcadeValue {
+                fn from(value: $ty) -> Self {
+                    ArcadeValue::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+from_primitive!(Boolean, bool);
+from_primitive!(Integer, i8, i16, i32, i64, u16, u32);
+from_primitive!(Float, f32, f64);
+from_primitive!(String, String, &str);
+from_primitive!(Link, RecordID);
+from_primitive!(Binary, Vec<u8>, &[u8]);
+
+impl<T: Into<ArcadeValue>> From<Option<T>> for ArcadeValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(ArcadeValue::Null)
+    }
+}
+
+impl<T: Into<ArcadeValue>> From<Vec<T>> for ArcadeValue {
+    fn from(values: Vec<T>) -> Self {
+        ArcadeValue::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<ArcadeValue>> From<HashMap<String, T>> for ArcadeValue {
+    fn from(entries: HashMap<String, T>) -> Self {
+        ArcadeValue::Map(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key, value.into()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+    use serde_json::{json, Value};
+
+    use crate::{ArcadeValue, RecordID};
+
+    #[test]
+    fn should_convert_from_json() {
+        let value = ArcadeValue::from(json!({
+            "name": "Keanu",
+            "born": 1964,
+            "rating": 8.7,
+            "director": "#12:3",
+            "views": u64::MAX,
+            "address": { "@type": "Address", "city": "Beirut" },
+            "tags": ["actor", null]
+        }));
+
+        assert_eq!(
+            Some("Keanu"),
+            value.get("name").and_then(ArcadeValue::as_str)
+        );
+        assert_eq!(Some(1964), value.get("born").and_then(ArcadeValue::as_i64));
+        assert_eq!(Some(8.7), value.get("rating").and_then(ArcadeValue::as_f64));
+        assert_eq!(
+            Some(RecordID::new(12, 3)),
+            value.get("director").and_then(ArcadeValue::as_link)
+        );
+        assert_eq!(
+            Some(&ArcadeValue::String("#12:3".to_string())),
+            value.get("director")
+        );
+        assert_eq!(
+            Some(&ArcadeValue::Decimal(u64::MAX.to_string())),
+            value.get("views")
+        );
+        assert!(matches!(
+            value.get("address"),
+            Some(ArcadeValue::Embedded(_))
+        ));
+        assert_eq!(
+            Some(&[ArcadeValue::from("actor"), ArcadeValue::Null][..]),
+            value.get("tags").and_then(ArcadeValue::as_list)
+        );
+        assert!(matches!(value, ArcadeValue::Map(_)));
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn should_keep_decimal_digits() {
+        let value: ArcadeValue = serde_json::from_str("0.1000000000000000055511").unwrap();
+
+        assert_eq!(
+            ArcadeValue::Decimal(String::from("0.1000000000000000055511")),
+            value
+        );
+        assert_eq!(
+            r#""0.1000000000000000055511""#,
+            serde_json::to_string(&value).unwrap()
+        );
+        assert_eq!(
+            ArcadeValue::Float(8.7),
+            serde_json::from_str::<ArcadeValue>("8.7").unwrap()
+        );
+    }
+
+    #[test]
+    fn should_convert_to_json() {
+        assert_eq!(json!(86400000), Value::from(ArcadeValue::Date(86400000)));
+        assert_eq!(
+            json!("0.1"),
+            Value::from(ArcadeValue::Decimal("0.1".into()))
+        );
+        assert_eq!(
+            json!("AQID"),
+            Value::from(ArcadeValue::from(vec![1u8, 2, 3]))
+        );
+        assert_eq!(
+            json!("#1:2"),
+            Value::from(ArcadeValue::from(RecordID::new(1, 2)))
+        );
+        assert_eq!(json!(null), Value::from(ArcadeValue::Float(f64::NAN)));
+        assert_eq!(
+            json!({ "ids": ["#1:2"] }),
+            Value::from(ArcadeValue::from(HashMap::from([(
+                "ids".to_string(),
+                vec![RecordID::new(1, 2)]
+            )])))
+        );
+    }
+
+    #[test]
+    fn should_serialize_like_json() {
+        let value = ArcadeValue::from(HashMap::from([(
+            "values".to_string(),
+            vec![
+                ArcadeValue::Date(86400000),
+                ArcadeValue::Decimal("0.1".into()),
+                ArcadeValue::from(vec![1u8, 2, 3]),
+                ArcadeValue::from(RecordID::new(1, 2)),
+                ArcadeValue::Float(f64::NAN),
+            ],
+        )]));
+
+        assert_eq!(
+            Value::from(value.clone()),
+            serde_json::to_value(&value).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_interpret_values() {
+        assert_eq!(Some(vec![1, 2, 3]), ArcadeValue::from("AQID").as_binary());
+        assert_eq!(Some(86400000), ArcadeValue::Integer(86400000).as_date());
+        assert_eq!(
+            Some("12.5".to_string()),
+            ArcadeValue::Float(12.5).as_decimal()
+        );
+    }
+
+    #[test]
+    fn should_deserialize_as_target() {
+        #[derive(Deserialize)]
+        struct Record {
+            #[serde(flatten)]
+            properties: HashMap<String, ArcadeValue>,
+        }
+
+        let record: Record =
+            serde_json::from_value(json!({ "@rid": "#1:2", "released": 1999 })).unwrap();
+
+        assert_eq!(
+            Some(RecordID::new(1, 2)),
+            record.properties.get("@rid").and_then(ArcadeValue::as_link)
+        );
+        assert_eq!(
+            Some(&ArcadeValue::Integer(1999)),
+            record.properties.get("released")
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use std::{fmt::Display, str::FromStr};
+
+/// A server version, e.g. `23.9.1` parsed from `23.9.1 (build 5c5ad5b4/1695650000/main)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ServerVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl ServerVersion {
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl FromStr for ServerVersion {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let version = s
+            .split_whitespace()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("Invalid server version '{}'", s))?;
+        let mut parts = version
+            .split(['.', '-'])
+            .map(|part| part.parse::<u32>().ok());
+        match (parts.next().flatten(), parts.next().flatten()) {
+            (Some(major), Some(minor)) => Ok(ServerVersion::new(
+                major,
+                minor,
+                parts.next().flatten().unwrap_or(0),
+            )),
+            _ => Err(anyhow::anyhow!("Invalid server version '{}'", s)),
+        }
+    }
+}
+
+impl Display for ServerVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// What the connected server reports about itself, negotiated once per client.
+///
+/// Only the root user may read the full server information: for other users the
+/// capabilities come from the basic information, and `clustered` is `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capabilities {
+    pub version: ServerVersion,
+    pub server_name: Option<String>,
+    pub clustered: Option<bool>,
+}
+
+impl Capabilities {
+    /// Returns `true` if the server version is at least `major.minor`.
+    pub fn at_least(&self, major: u32, minor: u32) -> bool {
+        self.version >= ServerVersion::new(major, minor, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Capabilities, ServerVersion};
+
+    #[test]
+    fn should_parse_server_versions() {
+        assert_eq!(
+            ServerVersion::new(23, 9, 1),
+            "23.9.1 (build 5c5ad5b4/1695650000/main)".parse().unwrap()
+        );
+        assert_eq!(
+            ServerVersion::new(24, 1, 0),
+            "24.1.0-SNAPSHOT".parse().unwrap()
+        );
+        assert!("unknown".parse::<ServerVersion>().is_err());
+        assert!(ServerVersion::new(23, 10, 0) > ServerVersion::new(23, 9, 1));
+    }
+
+    #[test]
+    fn should_gate_on_version() {
+        let capabilities = Capabilities {
+            version: ServerVersion::new(23, 9, 1),
+            server_name: None,
+            clustered: Some(false),
+        };
+
+        assert!(capabilities.at_least(23, 9));
+        assert!(capabilities.at_least(22, 12));
+        assert!(!capabilities.at_least(23, 10));
+    }
+}
@@ -0,0 +1,67 @@
+mod test_utils;
+
+use arcadedb_rs::admin::{ExportFormat, ImportOptions};
+use test_utils::{db_name, existing_db, new_db};
+
+#[tokio::test]
+async fn should_backup_database() {
+    let db = existing_db("movies").await;
+
+    let summary = db.backup(None).await.unwrap();
+    assert!(summary.backup_file.is_some());
+}
+
+#[tokio::test]
+async fn should_export_and_import_database() {
+    let db = existing_db("movies").await;
+    let file = format!("/home/arcadedb/exports/{}.jsonl.tgz", db_name());
+
+    let summary = db.export(ExportFormat::Jsonl, &file).await.unwrap();
+    assert_eq!("export database", summary.operation);
+
+    let imported = new_db("should_export_and_import_database").await;
+    let summary = imported
+        .import(&file, &ImportOptions::new().commit_every(1000))
+        .await
+        .unwrap();
+    assert_eq!("import database", summary.operation);
+}
+
+#[tokio::test]
+async fn should_check_database() {
+    let db = existing_db("movies").await;
+
+    let report = db.check(false).await.unwrap();
+    assert!(!report.auto_fix);
+    assert!(report.is_healthy());
+}
+
+#[tokio::test]
+async fn should_manage_settings_lifecycle_and_stats() {
+    let db = new_db("should_manage_settings_lifecycle_and_stats").await;
+
+    db.set_setting("arcadedb.dateFormat", "yyyy-MM-dd")
+        .await
+        .unwrap();
+    let settings = db.settings().await.unwrap();
+    let setting = settings
+        .iter()
+        .find(|setting| setting.key == "arcadedb.dateFormat")
+        .unwrap();
+    assert_eq!(Some(serde_json::json!("yyyy-MM-dd")), setting.value);
+
+    db.command("create document type Tenant")
+        .send::<serde_json::Value>()
+        .await
+        .unwrap();
+    db.command("insert into Tenant set name = 'acme'")
+        .send::<serde_json::Value>()
+        .await
+        .unwrap();
+
+    let stats = db.stats().await.unwrap();
+    assert_eq!(Some(&1), stats.types.get("Tenant"));
+
+    db.close().await.unwrap();
+    db.open().await.unwrap();
+}
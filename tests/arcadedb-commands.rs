@@ -5,7 +5,9 @@ use serde_json::Value;
 use test_utils::{existing_db, new_db};
 use uuid::Uuid;
 
-use arcadedb_rs::{ArcadeDBError, ErrorResponse, Language, RecordID};
+use arcadedb_rs::{
+    g, typed_record, ArcadeDBError, ArcadeValue, ErrorResponse, Language, Link, RecordID,
+};
 
 #[tokio::test]
 async fn should_run_simple_query() {
@@ -96,6 +98,118 @@ async fn should_cypher_query_with_parameters() {
     assert_eq!(1999, results[0].released);
 }
 
+#[tokio::test]
+async fn should_run_gremlin_traversal() {
+    let db = existing_db("movies").await;
+
+    let traversal = g()
+        .V()
+        .has_label("Movie")
+        .has("title", "The Matrix")
+        .limit(1);
+
+    let results = db.gremlin(&traversal).send::<Movie>().await.unwrap();
+
+    assert_eq!(1, results.len());
+
+    assert_eq!("The Matrix", results[0].title);
+    assert_eq!("Welcome to the Real World", results[0].tagline);
+    assert_eq!(1999, results[0].released);
+}
+
+#[tokio::test]
+async fn should_deserialize_links() {
+    let db = existing_db("movies").await;
+
+    #[derive(Deserialize)]
+    struct Actor {
+        movies: Vec<Link<Movie>>,
+    }
+
+    let results = db
+        .query("select out('ACTED_IN') as movies from Person where name = :name")
+        .param("name", "Keanu Reeves")
+        .send::<Actor>()
+        .await
+        .unwrap();
+
+    assert_eq!(1, results.len());
+    assert!(!results[0].movies.is_empty());
+}
+
+#[tokio::test]
+async fn should_resolve_links() {
+    let db = existing_db("movies").await;
+
+    #[derive(Deserialize)]
+    struct Actor {
+        movies: Vec<Link<Movie>>,
+    }
+
+    let mut results = db
+        .query("select out('ACTED_IN').@rid as movies from Person where name = :name")
+        .param("name", "Keanu Reeves")
+        .send::<Actor>()
+        .await
+        .unwrap();
+
+    Link::resolve_all(results.iter_mut().flat_map(|actor| &mut actor.movies), &db)
+        .await
+        .unwrap();
+    assert!(results[0].movies.iter().all(Link::is_expanded));
+
+    let rid = results[0].movies[0].id();
+    let mut link = Link::<Movie>::Id(rid);
+    assert!(link.resolve(&db).await.is_ok());
+    assert_eq!(rid, link.id());
+}
+
+#[tokio::test]
+async fn should_query_with_arcade_values() {
+    let db = existing_db("movies").await;
+
+    let results = db
+        .query("select title, released from Movie where released = :released limit 1")
+        .param("released", ArcadeValue::Integer(1999))
+        .send::<ArcadeValue>()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        Some(1999),
+        results[0].get("released").and_then(ArcadeValue::as_i64)
+    );
+}
+
+#[tokio::test]
+async fn should_deserialize_by_type() {
+    let db = existing_db("movies").await;
+
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Person {
+        name: String,
+    }
+
+    typed_record! {
+        enum Node {
+            Movie(Movie),
+            Person(Person),
+        }
+    }
+
+    let results = db
+        .query("select from V where title = :title or name = :name")
+        .param("title", "The Matrix")
+        .param("name", "Keanu Reeves")
+        .send::<Node>()
+        .await
+        .unwrap();
+
+    assert!(results.iter().any(|node| matches!(node, Node::Movie(_))));
+    assert!(results.iter().any(|node| matches!(node, Node::Person(_))));
+}
+
 #[tokio::test]
 async fn should_exec_a_command_with_parameters() {
     let db = new_db("should_exec_a_command_with_parameters").await;
@@ -1,6 +1,6 @@
 mod test_utils;
 
-use arcadedb_rs::{ArcadeDBError, ErrorResponse};
+use arcadedb_rs::{ArcadeDB, ArcadeDBError, Auth, ErrorResponse, ServerMode, UserDefinition};
 use test_utils::{arcadedb, db_name};
 
 #[tokio::test]
@@ -42,3 +42,119 @@ async fn create_should_on_existing_db() {
 
     db.drop().await.unwrap();
 }
+
+#[tokio::test]
+async fn should_open_and_close_db() {
+    let arcade = arcadedb().await;
+
+    let name = db_name();
+    let db = arcade.db(&name);
+    db.create().await.unwrap();
+
+    assert_eq!("ok", arcade.close_database(&name).await.unwrap().result);
+    assert_eq!("ok", arcade.open_database(&name).await.unwrap().result);
+
+    db.drop().await.unwrap();
+}
+
+#[tokio::test]
+async fn should_create_and_drop_user() {
+    let arcade = arcadedb().await;
+
+    let name = db_name();
+    let user = UserDefinition::new(&name, "supersecret").database("movies", ["admin"]);
+
+    assert_eq!("ok", arcade.create_user(&user).await.unwrap().result);
+    assert_eq!("ok", arcade.drop_user(&name).await.unwrap().result);
+}
+
+#[tokio::test]
+async fn should_set_database_setting_and_read_events() {
+    let arcade = arcadedb().await;
+
+    let name = db_name();
+    let db = arcade.db(&name);
+    db.create().await.unwrap();
+
+    let result = arcade
+        .set_database_setting(&name, "arcadedb.dateFormat", "yyyy-MM-dd")
+        .await
+        .unwrap();
+    assert_eq!("ok", result.result);
+
+    let events = arcade.server_events(None).await.unwrap();
+    assert!(!events.files.is_empty());
+
+    db.drop().await.unwrap();
+}
+
+#[tokio::test]
+async fn should_log_in_as_created_user() {
+    let arcade = arcadedb().await;
+
+    let name = db_name();
+    let user = UserDefinition::new(&name, "supersecret").database("movies", ["admin"]);
+
+    arcade.create_user(&user).await.unwrap();
+
+    let client = ArcadeDB::builder()
+        .auth(Auth::basic(&name, "supersecret"))
+        .build("http://localhost:2480")
+        .await
+        .unwrap();
+    assert!(client.db("movies").exists().await.unwrap());
+
+    arcade.drop_user(&name).await.unwrap();
+}
+
+#[tokio::test]
+async fn should_get_server_info() {
+    let arcade = arcadedb().await;
+
+    assert!(arcade.ready().await);
+
+    let info = arcade.server_info(ServerMode::Default).await.unwrap();
+    assert!(info.server_version().is_ok());
+    assert!(!info.settings.is_empty());
+}
+
+#[tokio::test]
+async fn should_connect_eagerly() {
+    let arcade = ArcadeDB::builder()
+        .auth(Auth::basic("root", "playwithdata"))
+        .eager_connect(true)
+        .build("http://localhost:2480")
+        .await
+        .unwrap();
+    assert!(arcade.capabilities().unwrap().at_least(22, 1));
+
+    let result = ArcadeDB::builder()
+        .auth(Auth::basic("root", "wrong"))
+        .eager_connect(true)
+        .build("http://localhost:2480")
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn should_authenticate_with_session() {
+    let arcade = ArcadeDB::builder()
+        .auth(Auth::session("root", "playwithdata"))
+        .build("http://localhost:2480")
+        .await
+        .unwrap();
+
+    assert!(arcade.db("movies").exists().await.unwrap());
+
+    arcade.logout().await.unwrap();
+    assert!(arcade.db("movies").exists().await.unwrap());
+
+    let result = ArcadeDB::builder()
+        .auth(Auth::session("root", "wrong"))
+        .build("http://localhost:2480")
+        .await
+        .unwrap()
+        .databases()
+        .await;
+    assert!(result.is_err());
+}
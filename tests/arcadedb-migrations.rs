@@ -0,0 +1,87 @@
+mod test_utils;
+
+use arcadedb_rs::{
+    migration,
+    migrations::{Migration, MigrationState, Migrator},
+};
+use serde_json::Value;
+use test_utils::new_db;
+
+fn migrations() -> Vec<Migration> {
+    vec![
+        migration!("migrations/001_create_person.sqlscript").unwrap(),
+        migration!("migrations/002_seed_person.sql").unwrap(),
+        Migration::code(3, "seed_with_code", |tx| async move {
+            tx.command("insert into Person set name = :name")
+                .param("name", "Paul")
+                .send::<Value>()
+                .await
+                .map(|_| ())
+        }),
+    ]
+}
+
+#[tokio::test]
+async fn should_apply_pending_migrations() {
+    let db = new_db("should_apply_pending_migrations").await;
+
+    let migrator = Migrator::new(migrations()).unwrap();
+
+    let status = migrator.status(&db).await.unwrap();
+    assert!(status
+        .iter()
+        .all(|status| status.state == MigrationState::Pending));
+
+    assert_eq!(vec![1, 2, 3], migrator.up(&db).await.unwrap());
+    assert!(migrator.up(&db).await.unwrap().is_empty());
+
+    let people = db
+        .query("select from Person")
+        .send::<Value>()
+        .await
+        .unwrap();
+    assert_eq!(2, people.len());
+
+    let status = migrator.status(&db).await.unwrap();
+    assert!(status
+        .iter()
+        .all(|status| status.state == MigrationState::Applied));
+}
+
+#[tokio::test]
+async fn should_detect_checksum_drift() {
+    let db = new_db("should_detect_checksum_drift").await;
+
+    Migrator::new(migrations()).unwrap().up(&db).await.unwrap();
+
+    let mut changed = migrations();
+    changed[1] = Migration::sql(2, "seed_person", "insert into Person set name = 'Jane'");
+    let migrator = Migrator::new(changed).unwrap();
+
+    let status = migrator.status(&db).await.unwrap();
+    assert_eq!(MigrationState::Drifted, status[1].state);
+
+    let error = migrator.up(&db).await.unwrap_err();
+    assert_eq!(
+        "Applied migrations have been modified: 2_seed_person",
+        error.to_string()
+    );
+}
+
+#[tokio::test]
+async fn should_not_write_when_reading_status() {
+    let db = new_db("should_not_write_when_reading_status").await;
+
+    Migrator::new(migrations())
+        .unwrap()
+        .status(&db)
+        .await
+        .unwrap();
+
+    let types = db
+        .query("select from schema:types where name = '_migrations'")
+        .send::<Value>()
+        .await
+        .unwrap();
+    assert!(types.is_empty());
+}
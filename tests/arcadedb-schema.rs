@@ -0,0 +1,176 @@
+mod test_utils;
+
+use arcadedb_rs::schema::{
+    CreateIndex, CreateProperty, CreateType, DropProperty, DropType, IndexDefinition, IndexType,
+    NullStrategy, PropertyConstraint, PropertyDefinition, PropertyType, SchemaDefinition, SyncMode,
+    TypeDefinition, TypeKind,
+};
+use serde_json::{json, Value};
+use test_utils::new_db;
+
+#[tokio::test]
+async fn should_create_alter_drop_types_and_properties() {
+    let db = new_db("should_create_alter_drop_types_and_properties").await;
+
+    db.create_type(TypeKind::Vertex, "Person")
+        .if_not_exists()
+        .send(&db)
+        .await
+        .unwrap();
+    CreateType::new(TypeKind::Vertex, "Actor")
+        .extends("Person")
+        .send(&db)
+        .await
+        .unwrap();
+
+    CreateProperty::new("Person", "name", PropertyType::String)
+        .mandatory(true)
+        .not_null(true)
+        .send(&db)
+        .await
+        .unwrap();
+    db.alter_property("Person", "name", PropertyConstraint::Max(json!(20)))
+        .send(&db)
+        .await
+        .unwrap();
+
+    let error = db
+        .command("insert into Actor set surname = 'Reeves'")
+        .send::<Value>()
+        .await;
+    assert!(error.is_err());
+
+    db.command("insert into Actor set name = 'Keanu'")
+        .send::<Value>()
+        .await
+        .unwrap();
+
+    DropProperty::new("Person", "name")
+        .force()
+        .send(&db)
+        .await
+        .unwrap();
+    DropType::new("Actor")
+        .unsafe_drop()
+        .send(&db)
+        .await
+        .unwrap();
+    DropType::new("Person").if_exists().send(&db).await.unwrap();
+
+    let results = db
+        .query("select from schema:types where name = 'Person'")
+        .send::<Value>()
+        .await
+        .unwrap();
+    assert!(results.is_empty());
+}
+
+#[tokio::test]
+async fn should_create_list_rebuild_drop_indexes() {
+    let db = new_db("should_create_list_rebuild_drop_indexes").await;
+
+    CreateType::new(TypeKind::Document, "Person")
+        .send(&db)
+        .await
+        .unwrap();
+    CreateProperty::new("Person", "name", PropertyType::String)
+        .send(&db)
+        .await
+        .unwrap();
+
+    db.create_index("Person", ["name"], IndexType::Unique)
+        .null_strategy(NullStrategy::Skip)
+        .send(&db)
+        .await
+        .unwrap();
+
+    let indexes = db.indexes().await.unwrap();
+    let index = indexes
+        .iter()
+        .find(|index| index.name == "Person[name]")
+        .unwrap();
+    assert!(index.unique);
+    assert_eq!(vec!["name".to_string()], index.properties);
+
+    db.rebuild_index("Person[name]").await.unwrap();
+
+    db.drop_index("Person[name]").send(&db).await.unwrap();
+
+    let indexes = db.indexes().await.unwrap();
+    assert!(indexes.iter().all(|index| index.name != "Person[name]"));
+}
+
+#[tokio::test]
+async fn should_read_schema() {
+    let db = new_db("should_read_schema").await;
+
+    CreateType::new(TypeKind::Vertex, "Person")
+        .send(&db)
+        .await
+        .unwrap();
+    CreateType::new(TypeKind::Vertex, "Actor")
+        .extends("Person")
+        .send(&db)
+        .await
+        .unwrap();
+    CreateProperty::new("Person", "name", PropertyType::String)
+        .mandatory(true)
+        .send(&db)
+        .await
+        .unwrap();
+    CreateIndex::new("Person", ["name"], IndexType::NotUnique)
+        .send(&db)
+        .await
+        .unwrap();
+
+    let schema = db.schema().await.unwrap();
+
+    assert_eq!("should_read_schema", schema.database.name);
+
+    let person = schema.get_type("Person").unwrap();
+    assert_eq!(TypeKind::Vertex, person.kind);
+    assert!(!person.buckets.is_empty());
+
+    let actor = schema.get_type("Actor").unwrap();
+    assert_eq!(vec!["Person".to_string()], actor.super_types);
+    assert!(schema.is_subtype_of("Actor", "Person"));
+
+    let properties = schema.properties("Actor");
+    assert_eq!(1, properties.len());
+    assert_eq!("name", properties[0].name);
+    assert!(properties[0].mandatory);
+
+    assert_eq!(1, schema.indexes_of("Person").len());
+    assert!(schema
+        .buckets
+        .iter()
+        .any(|bucket| person.buckets.contains(&bucket.name)));
+}
+
+#[tokio::test]
+async fn should_sync_schema_definition() {
+    let db = new_db("should_sync_schema_definition").await;
+
+    let desired = SchemaDefinition::new()
+        .with_type(
+            TypeDefinition::new(TypeKind::Vertex, "Person")
+                .property(PropertyDefinition::new("name", PropertyType::String).mandatory(true)),
+        )
+        .with_type(TypeDefinition::new(TypeKind::Vertex, "Actor").extends("Person"))
+        .with_type(TypeDefinition::new(TypeKind::Edge, "ACTED_IN"))
+        .with_index(IndexDefinition::new("Person", ["name"], IndexType::Unique));
+
+    let planned = db.schema_sync(&desired, SyncMode::DryRun).await.unwrap();
+    assert_eq!(5, planned.len());
+    assert!(db.schema().await.unwrap().get_type("Person").is_none());
+
+    let applied = db.schema_sync(&desired, SyncMode::Apply).await.unwrap();
+    assert_eq!(planned, applied);
+
+    let schema = db.schema().await.unwrap();
+    assert!(schema.is_subtype_of("Actor", "Person"));
+    assert_eq!(1, schema.indexes_of("Person").len());
+
+    let changes = db.schema_diff(&desired).await.unwrap();
+    assert!(changes.is_empty(), "{:?}", changes);
+}
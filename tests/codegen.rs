@@ -0,0 +1,42 @@
+//! Compiles the code generated from `codegen/types.json`, which `codegen::tests` keeps in sync.
+
+#[allow(dead_code)]
+mod model {
+    include!("codegen/model.rs");
+}
+
+use model::{ActedIn, ActedIn2, Movie};
+
+#[test]
+fn should_read_generated_structs() {
+    let movie: Movie = serde_json::from_value(serde_json::json!({
+        "@rid": "#1:0",
+        "title": "Heat",
+        "crate": "box",
+        "rid": "shadowed",
+        "poster": "AAEC",
+        "thumbnail": null,
+        "budget": 1.5
+    }))
+    .unwrap();
+
+    assert_eq!("Heat", movie.title);
+    assert_eq!(Some("box"), movie.crate_.as_deref());
+    assert_eq!(Some("shadowed"), movie.rid_2.as_deref());
+    assert_eq!(vec![0, 1, 2], movie.poster);
+    assert_eq!(None, movie.thumbnail);
+    assert_eq!(Some(1.5), movie.budget.and_then(|budget| budget.as_f64()));
+
+    let edge: ActedIn = serde_json::from_value(serde_json::json!({
+        "@rid": "#2:0",
+        "@out": "#1:0",
+        "@in": "#3:0",
+        "out": "text"
+    }))
+    .unwrap();
+
+    assert_eq!(Some("text"), edge.out_2.as_deref());
+
+    let document: ActedIn2 = serde_json::from_value(serde_json::json!({})).unwrap();
+    assert!(document.rid.is_none());
+}
@@ -0,0 +1,67 @@
+// Generated by arcadedb-rs codegen. Do not edit.
+
+use std::collections::HashMap;
+
+use arcadedb_rs::RecordID;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Movie {
+    #[serde(rename = "@rid")]
+    pub rid: RecordID,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(rename = "crate", default, skip_serializing_if = "Option::is_none")]
+    pub crate_: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#try: Option<bool>,
+    #[serde(rename = "rid", default, skip_serializing_if = "Option::is_none")]
+    pub rid_2: Option<String>,
+    #[serde(rename = "1st", default, skip_serializing_if = "Option::is_none")]
+    pub field_1st: Option<i32>,
+    #[serde(with = "arcadedb_rs::convert::binary")]
+    pub poster: Vec<u8>,
+    #[serde(default, with = "arcadedb_rs::convert::binary::option", skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<Vec<u8>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frames: Option<Vec<serde_json::Value>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub director: Option<RecordID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<serde_json::Number>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ActedIn {
+    #[serde(rename = "@rid")]
+    pub rid: RecordID,
+    #[serde(rename = "@out")]
+    pub out: RecordID,
+    #[serde(rename = "@in")]
+    pub in_: RecordID,
+    #[serde(rename = "out", default, skip_serializing_if = "Option::is_none")]
+    pub out_2: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<String>,
+    #[serde(rename = "in_", default, skip_serializing_if = "Option::is_none")]
+    pub in_2: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roles: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Self_ {
+    #[serde(rename = "@rid", default, skip_serializing_if = "Option::is_none")]
+    pub rid: Option<RecordID>,
+    #[serde(rename = "super", default, skip_serializing_if = "Option::is_none")]
+    pub super_: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ActedIn2 {
+    #[serde(rename = "@rid", default, skip_serializing_if = "Option::is_none")]
+    pub rid: Option<RecordID>,
+}
@@ -0,0 +1 @@
+insert into Person set name = 'John'